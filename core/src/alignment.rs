@@ -14,6 +14,12 @@ pub enum Alignment {
 
     /// Fill the entire axis.
     Fill,
+
+    /// Align the text baselines of the contents.
+    ///
+    /// Contents without a baseline are aligned at the start of the axis, and
+    /// so are the contents of any axis other than the cross axis of a row.
+    Baseline,
 }
 
 impl From<Horizontal> for Alignment {
@@ -61,3 +67,56 @@ pub enum Vertical {
     /// Align bottom
     Bottom,
 }
+
+/// The distribution of the free space along the main axis of a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Justify {
+    /// Pack the contents at the start of the axis.
+    Start,
+
+    /// Pack the contents at the center of the axis.
+    Center,
+
+    /// Pack the contents at the end of the axis.
+    End,
+
+    /// Distribute the free space between the contents, without space at the
+    /// edges.
+    SpaceBetween,
+
+    /// Distribute the free space around the contents, with half the space at
+    /// the edges.
+    SpaceAround,
+
+    /// Distribute the free space evenly between the contents and the edges.
+    SpaceEvenly,
+}
+
+impl Justify {
+    /// Returns the leading space and the space between `count` items that
+    /// distribute the given `free` space.
+    pub fn distribute(self, free: f32, count: usize) -> (f32, f32) {
+        let free = free.max(0.0);
+
+        match self {
+            Justify::Start => (0.0, 0.0),
+            Justify::Center => (free / 2.0, 0.0),
+            Justify::End => (free, 0.0),
+            Justify::SpaceBetween if count > 1 => {
+                (0.0, free / (count - 1) as f32)
+            }
+            Justify::SpaceBetween => (0.0, 0.0),
+            Justify::SpaceAround if count > 0 => {
+                let space = free / count as f32;
+
+                (space / 2.0, space)
+            }
+            Justify::SpaceAround => (0.0, 0.0),
+            Justify::SpaceEvenly => {
+                let space = free / (count + 1) as f32;
+
+                (space, space)
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Control the fit of some content (like an image) within a space.
+use crate::Size;
+
+/// The strategy used to fit the contents of a widget to its bounding box.
+///
+/// Each variant of this enum is a strategy that can be applied for resolving
+/// differences in aspect ratio and size between the image being displayed and
+/// the space it is being displayed in.
+///
+/// These strategies mirror the `object-fit` property of CSS. See
+/// [Mozilla's docs][1] for a demonstration.
+///
+/// [1]: https://developer.mozilla.org/en-US/docs/Web/CSS/object-fit
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum ContentFit {
+    /// Scale as big as it can be without needing to crop or hide parts.
+    ///
+    /// The image will be scaled (preserving aspect ratio) so that it just fits
+    /// within the window. This won't distort the image or crop/hide any edges,
+    /// but if the image doesn't fit perfectly, there may be whitespace on the
+    /// top/bottom or left/right.
+    Contain,
+
+    /// Scale the image to cover all of the bounding box, cropping if needed.
+    ///
+    /// This doesn't distort the image, and it ensures that the widget's area
+    /// is completely covered, but it might crop off a bit of the edges of the
+    /// widget, particularly when there is a big difference between the aspect
+    /// ratio of the widget and the aspect ratio of the image.
+    Cover,
+
+    /// Distort the image so the widget is 100% covered without cropping.
+    ///
+    /// This stretches the image to fit the widget, without any whitespace or
+    /// cropping. However, because of the stretch, the image may look distorted
+    /// or elongated, particularly when there's a mismatch of aspect ratios.
+    Fill,
+
+    /// Don't resize or scale the image at all.
+    ///
+    /// This will not apply any transformations to the provided image, but also
+    /// means that unless you do the math yourself, the widget's area will not
+    /// be completely covered, or the image might be cropped.
+    None,
+
+    /// Scale the image down if it's too big for the space, but never scale it
+    /// up.
+    ///
+    /// This works much like [`Contain`](Self::Contain), except that if the
+    /// image would have been scaled up, it keeps its original resolution to
+    /// avoid the blurring that accompanies upscaling images.
+    ScaleDown,
+}
+
+impl ContentFit {
+    /// Attempt to apply the given fit for a content size within some bounds.
+    ///
+    /// The returned value is the recommended scaled size of the content.
+    pub fn fit(&self, content: Size, bounds: Size) -> Size {
+        let content_ar = content.width / content.height;
+        let bounds_ar = bounds.width / bounds.height;
+
+        match self {
+            Self::Contain => {
+                if bounds_ar > content_ar {
+                    Size {
+                        width: content.width * bounds.height / content.height,
+                        ..bounds
+                    }
+                } else {
+                    Size {
+                        height: content.height * bounds.width / content.width,
+                        ..bounds
+                    }
+                }
+            }
+            Self::Cover => {
+                if bounds_ar < content_ar {
+                    Size {
+                        width: content.width * bounds.height / content.height,
+                        ..bounds
+                    }
+                } else {
+                    Size {
+                        height: content.height * bounds.width / content.width,
+                        ..bounds
+                    }
+                }
+            }
+            Self::Fill => bounds,
+            Self::None => content,
+            Self::ScaleDown => {
+                if content.width <= bounds.width
+                    && content.height <= bounds.height
+                {
+                    content
+                } else {
+                    Self::Contain.fit(content, bounds)
+                }
+            }
+        }
+    }
+}
+
+impl Default for ContentFit {
+    fn default() -> Self {
+        ContentFit::Contain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: Size = Size {
+        width: 200.0,
+        height: 100.0,
+    };
+
+    #[test]
+    fn contain_keeps_aspect_ratio_inside_bounds() {
+        let size = ContentFit::Contain.fit(CONTENT, Size::new(100.0, 100.0));
+
+        assert_eq!(size, Size::new(100.0, 50.0));
+    }
+
+    #[test]
+    fn cover_keeps_aspect_ratio_over_bounds() {
+        let size = ContentFit::Cover.fit(CONTENT, Size::new(100.0, 100.0));
+
+        assert_eq!(size, Size::new(200.0, 100.0));
+    }
+
+    #[test]
+    fn scale_down_never_scales_up() {
+        let bounds = Size::new(400.0, 400.0);
+
+        assert_eq!(ContentFit::ScaleDown.fit(CONTENT, bounds), CONTENT);
+        assert_eq!(
+            ContentFit::ScaleDown.fit(CONTENT, Size::new(100.0, 100.0)),
+            Size::new(100.0, 50.0)
+        );
+    }
+}
@@ -0,0 +1,24 @@
+/// The direction in which content flows horizontally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Content flows from left to right, like in English.
+    LeftToRight,
+
+    /// Content flows from right to left, like in Hebrew or Arabic.
+    RightToLeft,
+}
+
+impl Direction {
+    /// Returns true if the [`Direction`] is [`RightToLeft`].
+    ///
+    /// [`RightToLeft`]: Self::RightToLeft
+    pub fn is_right_to_left(self) -> bool {
+        self == Direction::RightToLeft
+    }
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Direction::LeftToRight
+    }
+}
@@ -1,3 +1,5 @@
+use std::hash::{Hash, Hasher};
+
 /// A font.
 #[derive(Debug, Clone, Copy)]
 pub enum Font {
@@ -22,3 +24,17 @@ impl Default for Font {
         Font::Default
     }
 }
+
+impl Hash for Font {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Renderers identify external fonts by their name, so their bytes
+        // are not hashed.
+        match self {
+            Font::Default => 0.hash(state),
+            Font::External { name, .. } => {
+                1.hash(state);
+                name.hash(state);
+            }
+        }
+    }
+}
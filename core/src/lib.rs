@@ -20,6 +20,8 @@ pub mod mouse;
 
 mod background;
 mod color;
+mod content_fit;
+mod direction;
 mod font;
 mod length;
 mod padding;
@@ -31,6 +33,8 @@ mod vector;
 pub use alignment::Alignment;
 pub use background::Background;
 pub use color::Color;
+pub use content_fit::ContentFit;
+pub use direction::Direction;
 pub use font::Font;
 pub use length::Length;
 pub use padding::Padding;
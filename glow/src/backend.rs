@@ -7,7 +7,7 @@ use iced_graphics::backend;
 use iced_graphics::font;
 use iced_graphics::{Layer, Primitive};
 use iced_native::alignment;
-use iced_native::{Font, Size};
+use iced_native::{Font, Rectangle, Size};
 
 /// A [`glow`] graphics backend for [`iced`].
 ///
@@ -110,7 +110,7 @@ impl Backend {
             );
         }
 
-        if !layer.text.is_empty() {
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
             for text in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
                 let text = glow_glyph::Section {
@@ -176,6 +176,39 @@ impl Backend {
                 self.text_pipeline.queue(text);
             }
 
+            for rich_text in layer.rich_text.iter() {
+                // Spans are laid out together, like the text above
+                let text = glow_glyph::Section {
+                    screen_position: (
+                        (rich_text.bounds.x * scale_factor).round(),
+                        (rich_text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (rich_text.bounds.width * scale_factor).ceil(),
+                        (rich_text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: rich_text
+                        .spans
+                        .iter()
+                        .map(|span| glow_glyph::Text {
+                            text: &span.content,
+                            scale: glow_glyph::ab_glyph::PxScale {
+                                x: span.size * scale_factor,
+                                y: span.size * scale_factor,
+                            },
+                            font_id: self.text_pipeline.find_font(span.font),
+                            extra: glow_glyph::Extra {
+                                color: span.color.into_linear(),
+                                z: 0.0,
+                            },
+                        })
+                        .collect(),
+                    ..Default::default()
+                };
+
+                self.text_pipeline.queue(text);
+            }
+
             self.text_pipeline.draw_queued(
                 gl,
                 transformation,
@@ -215,6 +248,10 @@ impl backend::Text for Backend {
         self.text_pipeline.measure(contents, size, font, bounds)
     }
 
+    fn ascent(&self, size: f32, font: Font) -> f32 {
+        self.text_pipeline.ascent(size, font)
+    }
+
     fn hit_test(
         &self,
         contents: &str,
@@ -233,6 +270,22 @@ impl backend::Text for Backend {
             nearest_only,
         )
     }
+
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure_spans(spans, bounds)
+    }
+
+    fn span_regions(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> Vec<Vec<Rectangle>> {
+        self.text_pipeline.span_regions(spans, bounds)
+    }
 }
 
 #[cfg(feature = "image")]
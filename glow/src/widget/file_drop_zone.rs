@@ -0,0 +1,11 @@
+//! Receive files dropped from outside the application on some content.
+use crate::Renderer;
+
+pub use iced_graphics::file_drop_zone::{State, Style, StyleSheet};
+
+/// A wrapper that receives the files dragged and dropped over its bounds.
+///
+/// This is an alias of an `iced_native` file drop zone with a default
+/// `Renderer`.
+pub type FileDropZone<'a, Message> =
+    iced_native::widget::FileDropZone<'a, Message, Renderer>;
@@ -0,0 +1,10 @@
+//! Listen to pointer events on some content.
+use crate::Renderer;
+
+pub use iced_graphics::mouse_area::State;
+
+/// A transparent wrapper that produces messages on pointer events.
+///
+/// This is an alias of an `iced_native` mouse area with a default `Renderer`.
+pub type MouseArea<'a, Message> =
+    iced_native::widget::MouseArea<'a, Message, Renderer>;
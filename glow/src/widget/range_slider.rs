@@ -0,0 +1,7 @@
+//! Display an interactive selector of a range within a range of values.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+pub use iced_graphics::range_slider::{
+    Handle, HandleShape, Orientation, Style, StyleSheet, TickMark, TickMarks,
+};
+pub use iced_native::widget::range_slider::{RangeSlider, State};
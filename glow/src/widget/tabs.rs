@@ -0,0 +1,9 @@
+//! Switch between views using a bar of tabs.
+use crate::Renderer;
+
+pub use iced_graphics::tabs::{State, Style, StyleSheet, Tab};
+
+/// A bar of tabs on top of the content of the active one.
+///
+/// This is an alias of an `iced_native` tabs widget with a default `Renderer`.
+pub type Tabs<'a, Message> = iced_native::widget::Tabs<'a, Message, Renderer>;
@@ -2,7 +2,9 @@
 use iced_native::image;
 use iced_native::svg;
 use iced_native::text;
-use iced_native::{Font, Point, Size};
+use iced_native::{Font, Point, Rectangle, Size};
+
+use std::time::Duration;
 
 /// The graphics backend of a [`Renderer`].
 ///
@@ -45,6 +47,9 @@ pub trait Text {
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Returns the ascent of the given font at the given size.
+    fn ascent(&self, size: f32, font: Font) -> f32;
+
     /// Tests whether the provided point is within the boundaries of [`Text`]
     /// laid out with the given parameters, returning information about
     /// the nearest character.
@@ -61,12 +66,35 @@ pub trait Text {
         point: Point,
         nearest_only: bool,
     ) -> Option<text::Hit>;
+
+    /// Measures the given spans, laid out together as one paragraph,
+    /// returning the size of a laid out paragraph that fits in the provided
+    /// bounds.
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Returns the regions covered by each of the given spans, laid out
+    /// together as one paragraph in the provided bounds.
+    fn span_regions(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> Vec<Vec<Rectangle>>;
 }
 
 /// A graphics backend that supports image rendering.
 pub trait Image {
     /// Returns the dimensions of the provided image.
     fn dimensions(&self, handle: &image::Handle) -> (u32, u32);
+
+    /// Returns the delay of each frame of the provided image, if it is
+    /// animated.
+    fn frame_delays(&self, _handle: &image::Handle) -> Vec<Duration> {
+        Vec::new()
+    }
 }
 
 /// A graphics backend that supports SVG rendering.
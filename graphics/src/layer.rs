@@ -4,7 +4,8 @@ use crate::image;
 use crate::svg;
 use crate::triangle;
 use crate::{
-    Background, Font, Point, Primitive, Rectangle, Size, Vector, Viewport,
+    Background, Color, Font, Point, Primitive, Rectangle, Size, Span, Vector,
+    Viewport,
 };
 
 /// A group of primitives that should be clipped together.
@@ -22,6 +23,9 @@ pub struct Layer<'a> {
     /// The text of the [`Layer`].
     pub text: Vec<Text<'a>>,
 
+    /// The rich text of the [`Layer`].
+    pub rich_text: Vec<RichText<'a>>,
+
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
 }
@@ -34,6 +38,7 @@ impl<'a> Layer<'a> {
             quads: Vec::new(),
             meshes: Vec::new(),
             text: Vec::new(),
+            rich_text: Vec::new(),
             images: Vec::new(),
         }
     }
@@ -134,6 +139,14 @@ impl<'a> Layer<'a> {
                     vertical_alignment: *vertical_alignment,
                 });
             }
+            Primitive::RichText { spans, bounds } => {
+                let layer = &mut layers[current_layer];
+
+                layer.rich_text.push(RichText {
+                    spans,
+                    bounds: *bounds + translation,
+                });
+            }
             Primitive::Quad {
                 bounds,
                 background,
@@ -213,19 +226,33 @@ impl<'a> Layer<'a> {
                     current_layer,
                 );
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                frame,
+                filter_method,
+                rotation,
+                bounds,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
+                    frame: *frame,
+                    filter_method: *filter_method,
+                    rotation: *rotation,
                     bounds: *bounds + translation,
                 });
             }
-            Primitive::Svg { handle, bounds } => {
+            Primitive::Svg {
+                handle,
+                color,
+                bounds,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Vector {
                     handle: handle.clone(),
+                    color: *color,
                     bounds: *bounds + translation,
                 });
             }
@@ -296,6 +323,16 @@ pub struct Text<'a> {
     pub vertical_alignment: alignment::Vertical,
 }
 
+/// A paragraph of rich text, made of spans.
+#[derive(Debug, Clone, Copy)]
+pub struct RichText<'a> {
+    /// The spans of the [`RichText`].
+    pub spans: &'a [Span],
+
+    /// The layout bounds of the [`RichText`].
+    pub bounds: Rectangle,
+}
+
 /// A raster or vector image.
 #[derive(Debug, Clone)]
 pub enum Image {
@@ -304,6 +341,15 @@ pub enum Image {
         /// The handle of a raster image.
         handle: image::Handle,
 
+        /// The frame of the image to draw, if it is animated.
+        frame: usize,
+
+        /// The filter method used when scaling the image.
+        filter_method: image::FilterMethod,
+
+        /// The rotation of the image around the center of its bounds.
+        rotation: image::Rotation,
+
         /// The bounds of the image.
         bounds: Rectangle,
     },
@@ -312,6 +358,9 @@ pub enum Image {
         /// The handle of a vector image.
         handle: svg::Handle,
 
+        /// The color the image is tinted with, if any.
+        color: Option<Color>,
+
         /// The bounds of the image.
         bounds: Rectangle,
     },
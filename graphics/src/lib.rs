@@ -35,7 +35,7 @@ pub use antialiasing::Antialiasing;
 pub use backend::Backend;
 pub use error::Error;
 pub use layer::Layer;
-pub use primitive::Primitive;
+pub use primitive::{Primitive, Span};
 pub use renderer::Renderer;
 pub use transformation::Transformation;
 pub use viewport::Viewport;
@@ -34,6 +34,13 @@ pub enum Primitive {
         /// The vertical alignment of the text
         vertical_alignment: alignment::Vertical,
     },
+    /// A rich text primitive, made of spans laid out as one paragraph
+    RichText {
+        /// The spans of the text
+        spans: Vec<Span>,
+        /// The bounds of the text
+        bounds: Rectangle,
+    },
     /// A quad primitive
     Quad {
         /// The bounds of the quad
@@ -51,6 +58,12 @@ pub enum Primitive {
     Image {
         /// The handle of the image
         handle: image::Handle,
+        /// The frame of the image to draw, if it is animated
+        frame: usize,
+        /// The filter method used when scaling the image
+        filter_method: image::FilterMethod,
+        /// The rotation of the image around the center of its bounds
+        rotation: image::Rotation,
         /// The bounds of the image
         bounds: Rectangle,
     },
@@ -59,6 +72,9 @@ pub enum Primitive {
         /// The path of the SVG file
         handle: svg::Handle,
 
+        /// The color the SVG is tinted with, if any
+        color: Option<Color>,
+
         /// The bounds of the viewport
         bounds: Rectangle,
     },
@@ -104,3 +120,16 @@ impl Default for Primitive {
         Primitive::None
     }
 }
+
+/// A span of a [`Primitive::RichText`], with its own size, color and font.
+#[derive(Debug, Clone)]
+pub struct Span {
+    /// The contents of the span
+    pub content: String,
+    /// The size of the span
+    pub size: f32,
+    /// The color of the span
+    pub color: Color,
+    /// The font of the span
+    pub font: Font,
+}
@@ -1,6 +1,6 @@
 //! Create a renderer from a [`Backend`].
 use crate::backend::{self, Backend};
-use crate::{Primitive, Vector};
+use crate::{Primitive, Span, Vector};
 use iced_native::layout;
 use iced_native::renderer;
 use iced_native::text::{self, Text};
@@ -137,6 +137,10 @@ where
             .measure(content, f32::from(size), font, bounds)
     }
 
+    fn ascent(&self, size: u16, font: Font) -> f32 {
+        self.backend().ascent(f32::from(size), font)
+    }
+
     fn hit_test(
         &self,
         content: &str,
@@ -167,4 +171,39 @@ where
             vertical_alignment: text.vertical_alignment,
         });
     }
+
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Self::Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.backend().measure_spans(spans, bounds)
+    }
+
+    fn span_regions(
+        &self,
+        spans: &[text::Span<'_, Self::Font>],
+        bounds: Size,
+    ) -> Vec<Vec<Rectangle>> {
+        self.backend().span_regions(spans, bounds)
+    }
+
+    fn fill_spans(
+        &mut self,
+        spans: &[text::Span<'_, Self::Font>],
+        bounds: Rectangle,
+    ) {
+        self.primitives.push(Primitive::RichText {
+            spans: spans
+                .iter()
+                .map(|span| Span {
+                    content: span.content.to_string(),
+                    size: span.size,
+                    color: span.color,
+                    font: span.font,
+                })
+                .collect(),
+            bounds,
+        });
+    }
 }
@@ -10,22 +10,36 @@
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod draggable;
+pub mod drop_target;
+pub mod file_drop_zone;
+pub mod grid;
 pub mod image;
+pub mod mouse_area;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod range_slider;
+pub mod reorderable_column;
+pub mod responsive;
+pub mod rich_text;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod svg;
+pub mod table;
+pub mod tabs;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
+pub mod tree_view;
+pub mod virtual_list;
 
 mod column;
 mod row;
 mod space;
+mod stack;
 mod text;
 
 #[doc(no_inline)]
@@ -35,6 +49,16 @@ pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_target::DropTarget;
+#[doc(no_inline)]
+pub use file_drop_zone::FileDropZone;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use mouse_area::MouseArea;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -43,22 +67,39 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
+pub use reorderable_column::ReorderableColumn;
+#[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
+#[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
 
 pub use column::Column;
 pub use image::Image;
 pub use row::Row;
 pub use space::Space;
+pub use stack::Stack;
 pub use svg::Svg;
 pub use text::Text;
 
@@ -1,6 +1,7 @@
 //! Show toggle controls using checkboxes.
 use crate::Renderer;
 
+pub use iced_native::widget::checkbox::CheckState;
 pub use iced_style::checkbox::{Style, StyleSheet};
 
 /// A box that can be checked.
@@ -0,0 +1,12 @@
+//! Receive files dropped from outside the application on some content.
+use crate::Renderer;
+
+pub use iced_native::widget::file_drop_zone::State;
+pub use iced_style::drop_target::{Style, StyleSheet};
+
+/// A wrapper that receives the files dragged and dropped over its bounds.
+///
+/// This is an alias of an `iced_native` file drop zone with a default
+/// `Renderer`.
+pub type FileDropZone<'a, Message, Backend> =
+    iced_native::widget::FileDropZone<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,2 @@
+//! Play animated images, like GIFs.
+pub use iced_native::widget::image::animated::{AnimatedImage, State};
@@ -0,0 +1,8 @@
+//! Display an interactive selector of a range within a range of values.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+pub use iced_native::widget::range_slider::{RangeSlider, State};
+pub use iced_native::widget::slider::{Orientation, TickMark};
+pub use iced_style::slider::{
+    Handle, HandleShape, Style, StyleSheet, TickMarks,
+};
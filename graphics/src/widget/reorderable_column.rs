@@ -0,0 +1,12 @@
+//! Let users reorder a column of items by dragging them.
+use crate::Renderer;
+
+pub use iced_native::widget::reorderable_column::{Reorder, State};
+pub use iced_style::reorderable_column::{Style, StyleSheet};
+
+/// A column of items that users can reorder by dragging their handles.
+///
+/// This is an alias of an `iced_native` reorderable column with a default
+/// `Renderer`.
+pub type ReorderableColumn<'a, Message, Backend> =
+    iced_native::widget::ReorderableColumn<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,5 @@
+use crate::Renderer;
+
+/// A container that displays its contents on top of each other.
+pub type Stack<'a, Message, Backend> =
+    iced_native::widget::Stack<'a, Message, Renderer<Backend>>;
@@ -1,6 +1,6 @@
 //! Display vector graphics in your application.
 use crate::backend::{self, Backend};
-use crate::{Primitive, Rectangle, Renderer};
+use crate::{Color, Primitive, Rectangle, Renderer};
 use iced_native::svg;
 
 pub use iced_native::widget::svg::Svg;
@@ -14,7 +14,16 @@ where
         self.backend().viewport_dimensions(handle)
     }
 
-    fn draw(&mut self, handle: svg::Handle, bounds: Rectangle) {
-        self.draw_primitive(Primitive::Svg { handle, bounds })
+    fn draw(
+        &mut self,
+        handle: svg::Handle,
+        color: Option<Color>,
+        bounds: Rectangle,
+    ) {
+        self.draw_primitive(Primitive::Svg {
+            handle,
+            color,
+            bounds,
+        })
     }
 }
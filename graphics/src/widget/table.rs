@@ -0,0 +1,11 @@
+//! Display tabular data with sortable, resizable columns.
+use crate::Renderer;
+
+pub use iced_native::widget::table::{Column, SelectionMode, SortOrder, State};
+pub use iced_style::table::{Style, StyleSheet};
+
+/// A widget displaying rows of data in resizable, sortable columns.
+///
+/// This is an alias of an `iced_native` table with a default `Renderer`.
+pub type Table<'a, Message, Backend> =
+    iced_native::widget::Table<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,11 @@
+//! Switch between views using a bar of tabs.
+use crate::Renderer;
+
+pub use iced_native::widget::tabs::{State, Tab};
+pub use iced_style::tabs::{Style, StyleSheet};
+
+/// A bar of tabs on top of the content of the active one.
+///
+/// This is an alias of an `iced_native` tabs widget with a default `Renderer`.
+pub type Tabs<'a, Message, Backend> =
+    iced_native::widget::Tabs<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,12 @@
+//! Display hierarchical data in an expandable tree.
+use crate::Renderer;
+
+pub use iced_native::widget::tree_view::{Node, State};
+pub use iced_style::tree_view::{Style, StyleSheet};
+
+/// A widget displaying a hierarchy of nodes that can be expanded and
+/// collapsed.
+///
+/// This is an alias of an `iced_native` tree view with a default `Renderer`.
+pub type TreeView<'a, T, Message, Backend> =
+    iced_native::widget::TreeView<'a, T, Message, Renderer<Backend>>;
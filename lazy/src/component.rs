@@ -336,6 +336,13 @@ where
         .unwrap_or_default()
     }
 
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.with_overlay_maybe(|overlay| {
+            overlay.is_over(layout, cursor_position)
+        })
+        .unwrap_or_default()
+    }
+
     fn hash_layout(&self, state: &mut Hasher, position: Point) {
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
@@ -4,6 +4,7 @@ use crate::{Hasher, Rectangle};
 use std::hash::{Hash, Hasher as _};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// An [`Image`] handle.
 #[derive(Debug, Clone)]
@@ -111,6 +112,86 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// The filtering method used when an image is scaled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterMethod {
+    /// Blends neighboring pixels, producing smooth results.
+    Linear,
+
+    /// Picks the nearest pixel, keeping hard edges. Useful for pixel art.
+    Nearest,
+}
+
+impl Default for FilterMethod {
+    fn default() -> Self {
+        FilterMethod::Linear
+    }
+}
+
+/// A rotation of an image, in quarter turns clockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rotation {
+    /// The image is not rotated.
+    None,
+
+    /// The image is rotated by 90 degrees.
+    Quarter,
+
+    /// The image is rotated by 180 degrees.
+    Half,
+
+    /// The image is rotated by 270 degrees.
+    ThreeQuarters,
+}
+
+impl Rotation {
+    /// Returns the [`Rotation`] after turning this one by 90 degrees
+    /// clockwise.
+    pub fn clockwise(self) -> Self {
+        match self {
+            Rotation::None => Rotation::Quarter,
+            Rotation::Quarter => Rotation::Half,
+            Rotation::Half => Rotation::ThreeQuarters,
+            Rotation::ThreeQuarters => Rotation::None,
+        }
+    }
+
+    /// Returns the [`Rotation`] after turning this one by 90 degrees
+    /// counterclockwise.
+    pub fn counterclockwise(self) -> Self {
+        match self {
+            Rotation::None => Rotation::ThreeQuarters,
+            Rotation::Quarter => Rotation::None,
+            Rotation::Half => Rotation::Quarter,
+            Rotation::ThreeQuarters => Rotation::Half,
+        }
+    }
+
+    /// Returns the angle of the [`Rotation`] in radians.
+    pub fn radians(self) -> f32 {
+        let quarter_turns = match self {
+            Rotation::None => 0.0,
+            Rotation::Quarter => 1.0,
+            Rotation::Half => 2.0,
+            Rotation::ThreeQuarters => 3.0,
+        };
+
+        quarter_turns * std::f32::consts::FRAC_PI_2
+    }
+
+    /// Returns whether the [`Rotation`] swaps the width and the height of an
+    /// image.
+    pub fn is_sideways(self) -> bool {
+        matches!(self, Rotation::Quarter | Rotation::ThreeQuarters)
+    }
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Rotation::None
+    }
+}
+
 /// A [`Renderer`] that can render raster graphics.
 ///
 /// [renderer]: crate::renderer
@@ -124,6 +205,37 @@ pub trait Renderer: crate::Renderer {
     fn dimensions(&self, handle: &Self::Handle) -> (u32, u32);
 
     /// Draws an image with the given [`Handle`] and inside the provided
-    /// `bounds`.
-    fn draw(&mut self, handle: Self::Handle, bounds: Rectangle);
+    /// `bounds`, using the given [`FilterMethod`] when scaling it.
+    fn draw(
+        &mut self,
+        handle: Self::Handle,
+        filter_method: FilterMethod,
+        bounds: Rectangle,
+    );
+
+    /// Returns the delay of each frame of the animated image with the given
+    /// [`Handle`].
+    ///
+    /// It is empty if the image is not animated.
+    fn frame_delays(&self, _handle: &Self::Handle) -> Vec<Duration> {
+        Vec::new()
+    }
+
+    /// Draws the given frame of the animated image with the given [`Handle`]
+    /// inside the provided `bounds`.
+    ///
+    /// An image that is not animated only has a first frame.
+    ///
+    /// The `bounds` are the ones of the image before the [`Rotation`] is
+    /// applied around their center.
+    fn draw_frame(
+        &mut self,
+        handle: Self::Handle,
+        _frame: usize,
+        filter_method: FilterMethod,
+        _rotation: Rotation,
+        bounds: Rectangle,
+    ) {
+        self.draw(handle, filter_method, bounds)
+    }
 }
@@ -3,11 +3,12 @@ mod limits;
 mod node;
 
 pub mod flex;
+pub mod grid;
 
 pub use limits::Limits;
 pub use node::Node;
 
-use crate::{Point, Rectangle, Vector};
+use crate::{Direction, Point, Rectangle, Vector};
 
 /// The bounds of a [`Node`] and its children, using absolute coordinates.
 #[derive(Debug, Clone, Copy)]
@@ -53,6 +54,11 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Returns the layout [`Direction`] of the [`Node`].
+    pub fn direction(&self) -> Direction {
+        self.node.direction()
+    }
+
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     pub fn children(self) -> impl Iterator<Item = Layout<'a>> {
         self.node.children().iter().map(move |node| {
@@ -16,8 +16,11 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use crate::alignment::Justify;
 use crate::layout::{Limits, Node};
-use crate::{Alignment, Element, Padding, Point, Size};
+use crate::{Alignment, Element, Padding, Point, Size, Vector};
+
+use std::ops::Range;
 
 /// The main axis of a flex layout.
 #[derive(Debug)]
@@ -52,6 +55,40 @@ impl Axis {
     }
 }
 
+/// The options of a flex layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    /// The spacing between the items, and between the lines of a wrapped
+    /// layout.
+    pub spacing: f32,
+
+    /// The alignment of the items in the cross axis.
+    ///
+    /// If it is [`Alignment::Baseline`], the items of a horizontal layout are
+    /// aligned by the baseline of their text.
+    pub align_items: Alignment,
+
+    /// The distribution of the items in the main axis.
+    pub justify_content: Justify,
+
+    /// Whether the items that do not fit in the main axis flow onto new
+    /// lines.
+    ///
+    /// When wrapping, the items are laid out using their intrinsic size.
+    pub wrap: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            spacing: 0.0,
+            align_items: Alignment::Start,
+            justify_content: Justify::Start,
+            wrap: false,
+        }
+    }
+}
+
 /// Computes the flex layout with the given axis and limits, applying spacing,
 /// padding and alignment to the items as needed.
 ///
@@ -65,10 +102,57 @@ pub fn resolve<Message, Renderer>(
     align_items: Alignment,
     items: &[Element<'_, Message, Renderer>],
 ) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    resolve_with_options(
+        axis,
+        renderer,
+        limits,
+        padding,
+        Options {
+            spacing,
+            align_items,
+            ..Options::default()
+        },
+        items,
+    )
+}
+
+/// Computes the flex layout with the given axis, limits and [`Options`],
+/// applying spacing, padding, alignment, justification and wrapping to the
+/// items as needed.
+///
+/// If the direction of the `limits` is right-to-left, the resulting layout
+/// is mirrored horizontally. Rows flow from right to left and the items of a
+/// column are aligned as if their start was on the right.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve_with_options<Message, Renderer>(
+    axis: Axis,
+    renderer: &Renderer,
+    limits: &Limits,
+    padding: Padding,
+    options: Options,
+    items: &[Element<'_, Message, Renderer>],
+) -> Node
 where
     Renderer: crate::Renderer,
 {
     let limits = limits.pad(padding);
+
+    if options.wrap {
+        return resolve_wrapped(
+            axis, renderer, &limits, padding, options, items,
+        );
+    }
+
+    let Options {
+        spacing,
+        align_items,
+        justify_content,
+        ..
+    } = options;
     let total_spacing = spacing * items.len().saturating_sub(1) as f32;
     let max_cross = axis.cross(limits.max());
 
@@ -93,7 +177,8 @@ where
                 let (max_width, max_height) = axis.pack(available, max_cross);
 
                 let child_limits =
-                    Limits::new(Size::ZERO, Size::new(max_width, max_height));
+                    Limits::new(Size::ZERO, Size::new(max_width, max_height))
+                        .with_direction(limits.direction());
 
                 let layout = child.layout(renderer, &child_limits);
                 let size = layout.size();
@@ -128,7 +213,8 @@ where
             let child_limits = Limits::new(
                 Size::new(min_width, min_height),
                 Size::new(max_width, max_height),
-            );
+            )
+            .with_direction(limits.direction());
 
             let layout = child.layout(renderer, &child_limits);
             let size = layout.size();
@@ -177,7 +263,8 @@ where
             let child_limits = Limits::new(
                 Size::new(min_width, min_height),
                 Size::new(max_width, max_height),
-            );
+            )
+            .with_direction(limits.direction());
 
             let layout = child.layout(renderer, &child_limits);
 
@@ -189,6 +276,17 @@ where
         }
     }
 
+    let offsets = match axis {
+        Axis::Horizontal if align_items == Alignment::Baseline => {
+            let (offsets, baseline_cross) = baseline_offsets(&nodes);
+
+            cross = cross.max(baseline_cross);
+
+            offsets
+        }
+        _ => vec![0.0; nodes.len()],
+    };
+
     let pad = axis.pack(padding.left as f32, padding.top as f32);
     let mut main = pad.0;
 
@@ -197,7 +295,7 @@ where
             main += spacing;
         }
 
-        let (x, y) = axis.pack(main, pad.1);
+        let (x, y) = axis.pack(main, pad.1 + offsets[i]);
 
         node.move_to(Point::new(x, y));
 
@@ -226,5 +324,211 @@ where
     let (width, height) = axis.pack(main - pad.0, cross);
     let size = limits.resolve(Size::new(width, height));
 
-    Node::with_children(size.pad(padding), nodes)
+    if justify_content != Justify::Start {
+        let (leading, between) = justify_content
+            .distribute(axis.main(size) - (main - pad.0), nodes.len());
+
+        for (i, node) in nodes.iter_mut().enumerate() {
+            let (x, y) = axis.pack(leading + between * i as f32, 0.0);
+
+            *node = node.clone().translate(Vector::new(x, y));
+        }
+    }
+
+    if limits.direction().is_right_to_left() {
+        for node in &mut nodes {
+            node.mirror(f32::from(padding.left), size.width);
+        }
+    }
+
+    with_baseline(Node::with_children(size.pad(padding), nodes))
+}
+
+/// Computes a flex layout that flows the items onto multiple lines.
+///
+/// The provided `limits` must be already padded.
+fn resolve_wrapped<Message, Renderer>(
+    axis: Axis,
+    renderer: &Renderer,
+    limits: &Limits,
+    padding: Padding,
+    options: Options,
+    items: &[Element<'_, Message, Renderer>],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let Options {
+        spacing,
+        align_items,
+        justify_content,
+        ..
+    } = options;
+
+    let max_main = axis.main(limits.max());
+    let child_limits = Limits::new(Size::ZERO, limits.max())
+        .with_direction(limits.direction());
+
+    let mut nodes: Vec<Node> = items
+        .iter()
+        .map(|child| child.layout(renderer, &child_limits))
+        .collect();
+
+    let mut lines: Vec<Range<usize>> = Vec::new();
+    let mut start = 0;
+    let mut line_main = 0.0;
+
+    for (i, node) in nodes.iter().enumerate() {
+        let main = axis.main(node.size());
+
+        if i == start {
+            line_main = main;
+        } else if line_main + spacing + main > max_main {
+            lines.push(start..i);
+
+            start = i;
+            line_main = main;
+        } else {
+            line_main += spacing + main;
+        }
+    }
+
+    if start < nodes.len() {
+        lines.push(start..nodes.len());
+    }
+
+    let line_sizes: Vec<(f32, f32)> = lines
+        .iter()
+        .map(|line| {
+            let line_nodes = &nodes[line.clone()];
+
+            let main = line_nodes
+                .iter()
+                .map(|node| axis.main(node.size()))
+                .sum::<f32>()
+                + spacing * line.len().saturating_sub(1) as f32;
+
+            let cross = match axis {
+                Axis::Horizontal if align_items == Alignment::Baseline => {
+                    baseline_offsets(line_nodes).1
+                }
+                _ => line_nodes
+                    .iter()
+                    .map(|node| axis.cross(node.size()))
+                    .fold(0.0, f32::max),
+            };
+
+            (main, cross)
+        })
+        .collect();
+
+    let content_main =
+        line_sizes.iter().map(|(main, _)| *main).fold(0.0, f32::max);
+    let content_cross = line_sizes.iter().map(|(_, cross)| *cross).sum::<f32>()
+        + spacing * lines.len().saturating_sub(1) as f32;
+
+    let (width, height) = axis.pack(content_main, content_cross);
+    let size = limits.resolve(Size::new(width, height));
+
+    let pad = axis.pack(padding.left as f32, padding.top as f32);
+    let mut cross = pad.1;
+
+    for (line, (line_main, line_cross)) in lines.into_iter().zip(line_sizes) {
+        let (leading, between) =
+            justify_content.distribute(axis.main(size) - line_main, line.len());
+
+        let mut main = pad.0 + leading;
+
+        let offsets = match axis {
+            Axis::Horizontal if align_items == Alignment::Baseline => {
+                baseline_offsets(&nodes[line.clone()]).0
+            }
+            _ => vec![0.0; line.len()],
+        };
+
+        for (i, offset) in line.zip(offsets) {
+            if align_items == Alignment::Fill {
+                let (width, height) =
+                    axis.pack(axis.main(nodes[i].size()), line_cross);
+                let fill = Size::new(width, height);
+
+                nodes[i] = items[i].layout(
+                    renderer,
+                    &Limits::new(fill, fill).with_direction(limits.direction()),
+                );
+            }
+
+            let node = &mut nodes[i];
+            let (x, y) = axis.pack(main, cross + offset);
+
+            node.move_to(Point::new(x, y));
+
+            match axis {
+                Axis::Horizontal => {
+                    node.align(
+                        Alignment::Start,
+                        align_items,
+                        Size::new(0.0, line_cross),
+                    );
+                }
+                Axis::Vertical => {
+                    node.align(
+                        align_items,
+                        Alignment::Start,
+                        Size::new(line_cross, 0.0),
+                    );
+                }
+            }
+
+            main += axis.main(node.size()) + spacing + between;
+        }
+
+        cross += line_cross + spacing;
+    }
+
+    if limits.direction().is_right_to_left() {
+        for node in &mut nodes {
+            node.mirror(f32::from(padding.left), size.width);
+        }
+    }
+
+    with_baseline(Node::with_children(size.pad(padding), nodes))
+}
+
+/// Computes the offsets on the cross axis of a row that align the baselines
+/// of the given nodes, along with the cross size needed to fit them.
+///
+/// Nodes without a baseline are not moved.
+fn baseline_offsets(nodes: &[Node]) -> (Vec<f32>, f32) {
+    let max_baseline =
+        nodes.iter().filter_map(Node::baseline).fold(0.0, f32::max);
+
+    let offsets: Vec<f32> = nodes
+        .iter()
+        .map(|node| match node.baseline() {
+            Some(baseline) => max_baseline - baseline,
+            None => 0.0,
+        })
+        .collect();
+
+    let cross = nodes
+        .iter()
+        .zip(&offsets)
+        .map(|(node, offset)| offset + node.size().height)
+        .fold(0.0, f32::max);
+
+    (offsets, cross)
+}
+
+/// Sets the baseline of a flex layout to the baseline of its first child that
+/// has one.
+fn with_baseline(node: Node) -> Node {
+    let baseline = node.children().iter().find_map(|child| {
+        child.baseline().map(|baseline| child.bounds().y + baseline)
+    });
+
+    match baseline {
+        Some(baseline) => node.with_baseline(baseline),
+        None => node,
+    }
 }
@@ -0,0 +1,521 @@
+//! Distribute elements in a grid of columns and rows.
+use crate::layout::{Limits, Node};
+use crate::{Alignment, Element, Length, Padding, Point, Size};
+
+/// The placement of an element in a grid layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Placement {
+    /// The column of the element, if placed explicitly.
+    pub column: Option<usize>,
+
+    /// The row of the element, if placed explicitly.
+    pub row: Option<usize>,
+
+    /// The amount of columns spanned by the element.
+    pub column_span: usize,
+
+    /// The amount of rows spanned by the element.
+    pub row_span: usize,
+
+    /// The horizontal alignment of the element inside its area.
+    pub align_x: Alignment,
+
+    /// The vertical alignment of the element inside its area.
+    pub align_y: Alignment,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Placement {
+            column: None,
+            row: None,
+            column_span: 1,
+            row_span: 1,
+            align_x: Alignment::Start,
+            align_y: Alignment::Start,
+        }
+    }
+}
+
+/// Computes the grid layout of the given items with the provided column and
+/// row tracks, applying padding and spacing as needed.
+///
+/// The `width` of the `spacing` separates the columns, while its `height`
+/// separates the rows.
+///
+/// Items without an explicit position are placed in the first free area,
+/// in row-major order. Rows are added as needed, using [`Length::Shrink`] when
+/// no track is provided for them.
+///
+/// If the direction of the `limits` is right-to-left, the first column is
+/// placed on the right.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve<Message, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    padding: Padding,
+    spacing: Size,
+    columns: &[Length],
+    rows: &[Length],
+    items: &[(Placement, Element<'_, Message, Renderer>)],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.pad(padding);
+    let max = limits.max();
+    let direction = limits.direction();
+
+    let column_tracks: Vec<Length> = if columns.is_empty() {
+        vec![Length::Fill]
+    } else {
+        columns.to_vec()
+    };
+
+    let placements: Vec<Placement> =
+        items.iter().map(|(placement, _)| *placement).collect();
+    let areas = place(column_tracks.len(), &placements);
+
+    let row_count = areas
+        .iter()
+        .map(|area| area.row + area.row_span)
+        .max()
+        .unwrap_or(0)
+        .max(rows.len());
+
+    let row_tracks: Vec<Length> = (0..row_count)
+        .map(|i| rows.get(i).copied().unwrap_or(Length::Shrink))
+        .collect();
+
+    let column_widths = size_tracks(
+        &column_tracks,
+        max.width,
+        spacing.width,
+        areas.iter().zip(items).map(|(area, (_, item))| {
+            (area.column, area.column_span, move || {
+                let limits = Limits::new(
+                    Size::ZERO,
+                    Size::new(max.width, f32::INFINITY),
+                )
+                .with_direction(direction);
+
+                item.layout(renderer, &limits).size().width
+            })
+        }),
+    );
+
+    let row_heights = size_tracks(
+        &row_tracks,
+        max.height,
+        spacing.height,
+        areas.iter().zip(items).map(|(area, (_, item))| {
+            let width = span(&column_widths, spacing.width, area.columns());
+
+            (area.row, area.row_span, move || {
+                let limits =
+                    Limits::new(Size::ZERO, Size::new(width, f32::INFINITY))
+                        .with_direction(direction);
+
+                item.layout(renderer, &limits).size().height
+            })
+        }),
+    );
+
+    let mut nodes: Vec<Node> = areas
+        .iter()
+        .zip(items)
+        .map(|(area, (placement, item))| {
+            let x = span(&column_widths, spacing.width, 0..area.column)
+                + if area.column > 0 { spacing.width } else { 0.0 };
+            let y = span(&row_heights, spacing.height, 0..area.row)
+                + if area.row > 0 { spacing.height } else { 0.0 };
+
+            let space = Size::new(
+                span(&column_widths, spacing.width, area.columns()),
+                span(&row_heights, spacing.height, area.rows()),
+            );
+
+            let min = Size::new(
+                if placement.align_x == Alignment::Fill {
+                    space.width
+                } else {
+                    0.0
+                },
+                if placement.align_y == Alignment::Fill {
+                    space.height
+                } else {
+                    0.0
+                },
+            );
+
+            let mut node = item.layout(
+                renderer,
+                &Limits::new(min, space).with_direction(direction),
+            );
+
+            node.move_to(Point::new(
+                x + f32::from(padding.left),
+                y + f32::from(padding.top),
+            ));
+            node.align(placement.align_x, placement.align_y, space);
+
+            node
+        })
+        .collect();
+
+    let intrinsic = Size::new(
+        span(&column_widths, spacing.width, 0..column_widths.len()),
+        span(&row_heights, spacing.height, 0..row_heights.len()),
+    );
+
+    let size = limits.resolve(intrinsic);
+
+    if direction.is_right_to_left() {
+        for node in &mut nodes {
+            node.mirror(f32::from(padding.left), size.width);
+        }
+    }
+
+    Node::with_children(size.pad(padding), nodes)
+}
+
+/// The resolved area of an item in the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Area {
+    column: usize,
+    row: usize,
+    column_span: usize,
+    row_span: usize,
+}
+
+impl Area {
+    fn columns(&self) -> std::ops::Range<usize> {
+        self.column..self.column + self.column_span
+    }
+
+    fn rows(&self) -> std::ops::Range<usize> {
+        self.row..self.row + self.row_span
+    }
+}
+
+/// Places the items in a grid with the given amount of columns.
+///
+/// Items with an explicit column and row are placed first. The rest are
+/// placed in the first free area after the previous automatic item.
+fn place(column_count: usize, placements: &[Placement]) -> Vec<Area> {
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let mut areas = vec![Area::default(); placements.len()];
+
+    let fits = |occupied: &Vec<Vec<bool>>, area: Area| {
+        area.rows().all(|row| match occupied.get(row) {
+            Some(cells) => area.columns().all(|column| !cells[column]),
+            None => true,
+        })
+    };
+
+    let mark = |occupied: &mut Vec<Vec<bool>>, area: Area| {
+        for row in area.rows() {
+            if occupied.len() <= row {
+                occupied.resize(row + 1, vec![false; column_count]);
+            }
+
+            for column in area.columns() {
+                occupied[row][column] = true;
+            }
+        }
+    };
+
+    let is_explicit = |placement: &Placement| {
+        placement.column.is_some() && placement.row.is_some()
+    };
+
+    for (i, placement) in placements.iter().enumerate() {
+        if !is_explicit(placement) {
+            continue;
+        }
+
+        let column_span = placement.column_span.max(1).min(column_count);
+
+        let area = Area {
+            column: placement
+                .column
+                .unwrap_or(0)
+                .min(column_count - column_span),
+            row: placement.row.unwrap_or(0),
+            column_span,
+            row_span: placement.row_span.max(1),
+        };
+
+        mark(&mut occupied, area);
+        areas[i] = area;
+    }
+
+    let mut cursor = (0, 0);
+
+    for (i, placement) in placements.iter().enumerate() {
+        if is_explicit(placement) {
+            continue;
+        }
+
+        let column_span = placement.column_span.max(1).min(column_count);
+        let row_span = placement.row_span.max(1);
+
+        let area_at = |row, column| Area {
+            column,
+            row,
+            column_span,
+            row_span,
+        };
+
+        let area = match (placement.column, placement.row) {
+            (Some(column), _) => {
+                let column = column.min(column_count - column_span);
+
+                (0..)
+                    .map(|row| area_at(row, column))
+                    .find(|area| fits(&occupied, *area))
+                    .unwrap()
+            }
+            (None, Some(row)) => (0..=column_count - column_span)
+                .map(|column| area_at(row, column))
+                .find(|area| fits(&occupied, *area))
+                .unwrap_or_else(|| area_at(row, 0)),
+            (None, None) => {
+                let (mut row, mut column) = cursor;
+
+                loop {
+                    if column + column_span > column_count {
+                        row += 1;
+                        column = 0;
+                    } else if fits(&occupied, area_at(row, column)) {
+                        break;
+                    } else {
+                        column += 1;
+                    }
+                }
+
+                cursor = (row, column + column_span);
+
+                area_at(row, column)
+            }
+        };
+
+        mark(&mut occupied, area);
+        areas[i] = area;
+    }
+
+    areas
+}
+
+/// Computes the sizes of the given tracks.
+///
+/// Fixed tracks take their exact size. Shrink tracks take the size of their
+/// largest item, while fill tracks share the remaining space. If the
+/// available space is infinite, fill tracks behave like shrink tracks.
+///
+/// The size of an item is only measured when it spans a track that depends
+/// on it.
+fn size_tracks<F>(
+    tracks: &[Length],
+    available: f32,
+    spacing: f32,
+    items: impl Iterator<Item = (usize, usize, F)>,
+) -> Vec<f32>
+where
+    F: FnOnce() -> f32,
+{
+    let is_finite = available.is_finite();
+
+    let is_intrinsic = |track: &Length| match track {
+        Length::Shrink => true,
+        Length::Fill | Length::FillPortion(_) => !is_finite,
+        Length::Units(_) => false,
+    };
+
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| match track {
+            Length::Units(units) => f32::from(*units),
+            _ => 0.0,
+        })
+        .collect();
+
+    let mut spanning = Vec::new();
+
+    for (start, span_length, measure) in items {
+        let range = start..start + span_length;
+
+        if !tracks[range.clone()].iter().any(is_intrinsic) {
+            continue;
+        }
+
+        let size = measure();
+
+        if span_length == 1 {
+            sizes[start] = sizes[start].max(size);
+        } else {
+            spanning.push((range, size));
+        }
+    }
+
+    for (range, size) in spanning {
+        let covered = span(&sizes, spacing, range.clone());
+
+        if size > covered {
+            let intrinsic: Vec<usize> =
+                range.filter(|i| is_intrinsic(&tracks[*i])).collect();
+
+            let extra = (size - covered) / intrinsic.len() as f32;
+
+            for i in intrinsic {
+                sizes[i] += extra;
+            }
+        }
+    }
+
+    if is_finite {
+        let fill_sum: u32 = tracks
+            .iter()
+            .map(|track| u32::from(track.fill_factor()))
+            .sum();
+
+        if fill_sum > 0 {
+            let used = sizes
+                .iter()
+                .zip(tracks)
+                .filter(|(_, track)| track.fill_factor() == 0)
+                .map(|(size, _)| *size)
+                .sum::<f32>();
+
+            let remaining = (available
+                - used
+                - spacing * tracks.len().saturating_sub(1) as f32)
+                .max(0.0);
+
+            for (size, track) in sizes.iter_mut().zip(tracks) {
+                let fill_factor = track.fill_factor();
+
+                if fill_factor > 0 {
+                    *size =
+                        remaining * f32::from(fill_factor) / fill_sum as f32;
+                }
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the total size of the given range of tracks, including the spacing
+/// between them.
+fn span(sizes: &[f32], spacing: f32, range: std::ops::Range<usize>) -> f32 {
+    let count = range.len();
+
+    sizes[range].iter().sum::<f32>() + spacing * count.saturating_sub(1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auto(column_span: usize, row_span: usize) -> Placement {
+        Placement {
+            column_span,
+            row_span,
+            ..Placement::default()
+        }
+    }
+
+    fn area(column: usize, row: usize) -> Area {
+        Area {
+            column,
+            row,
+            column_span: 1,
+            row_span: 1,
+        }
+    }
+
+    #[test]
+    fn automatic_placement_wraps_rows() {
+        let areas = place(2, &[auto(1, 1), auto(1, 1), auto(1, 1)]);
+
+        assert_eq!(areas, vec![area(0, 0), area(1, 0), area(0, 1)]);
+    }
+
+    #[test]
+    fn automatic_placement_skips_spanned_areas() {
+        let areas = place(3, &[auto(1, 2), auto(2, 1), auto(1, 1), auto(1, 1)]);
+
+        assert_eq!(
+            areas,
+            vec![
+                Area {
+                    row_span: 2,
+                    ..area(0, 0)
+                },
+                Area {
+                    column_span: 2,
+                    ..area(1, 0)
+                },
+                area(1, 1),
+                area(2, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn explicit_placement_is_resolved_first() {
+        let explicit = Placement {
+            column: Some(0),
+            row: Some(0),
+            ..Placement::default()
+        };
+
+        let areas = place(2, &[auto(1, 1), explicit]);
+
+        assert_eq!(areas, vec![area(1, 0), area(0, 0)]);
+    }
+
+    #[test]
+    fn spans_are_clamped_to_column_count() {
+        let areas = place(2, &[auto(3, 1)]);
+
+        assert_eq!(
+            areas,
+            vec![Area {
+                column_span: 2,
+                ..area(0, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn fill_tracks_share_remaining_space() {
+        let sizes = size_tracks::<fn() -> f32>(
+            &[Length::Units(100), Length::Fill, Length::FillPortion(3)],
+            520.0,
+            10.0,
+            std::iter::empty(),
+        );
+
+        assert_eq!(sizes, vec![100.0, 100.0, 300.0]);
+    }
+
+    #[test]
+    fn shrink_tracks_fit_their_items() {
+        let sizes = size_tracks(
+            &[Length::Shrink, Length::Shrink],
+            f32::INFINITY,
+            10.0,
+            vec![
+                (0, 1, (|| 50.0) as fn() -> f32),
+                (0, 1, || 80.0),
+                (0, 2, || 200.0),
+            ]
+            .into_iter(),
+        );
+
+        assert_eq!(sizes, vec![135.0, 55.0]);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Length, Padding, Size};
+use crate::{Direction, Length, Padding, Size};
 
 /// A set of size constraints for layouting.
 #[derive(Debug, Clone, Copy)]
@@ -6,6 +6,8 @@ pub struct Limits {
     min: Size,
     max: Size,
     fill: Size,
+    aspect_ratio: Option<(f32, Size)>,
+    direction: Direction,
 }
 
 impl Limits {
@@ -14,6 +16,8 @@ impl Limits {
         min: Size::ZERO,
         max: Size::INFINITY,
         fill: Size::INFINITY,
+        aspect_ratio: None,
+        direction: Direction::LeftToRight,
     };
 
     /// Creates new [`Limits`] with the given minimum and maximum [`Size`].
@@ -22,6 +26,8 @@ impl Limits {
             min,
             max,
             fill: Size::INFINITY,
+            aspect_ratio: None,
+            direction: Direction::LeftToRight,
         }
     }
 
@@ -40,6 +46,21 @@ impl Limits {
         self.fill
     }
 
+    /// Returns the layout [`Direction`] of the [`Limits`].
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Sets the layout [`Direction`] of the current [`Limits`].
+    ///
+    /// Containers pass the [`Direction`] down to the [`Limits`] of their
+    /// children, so it is inherited by the whole subtree.
+    pub fn with_direction(mut self, direction: Direction) -> Limits {
+        self.direction = direction;
+
+        self
+    }
+
     /// Applies a width constraint to the current [`Limits`].
     pub fn width(mut self, width: Length) -> Limits {
         match width {
@@ -116,6 +137,20 @@ impl Limits {
         self
     }
 
+    /// Applies an aspect ratio constraint to the current [`Limits`].
+    ///
+    /// The `ratio` is the width divided by the height. When resolved, the
+    /// height is derived from the width, unless the height constraints do not
+    /// allow it. In that case, the width is derived from the height instead.
+    ///
+    /// The constraint is kept when the [`Limits`] are shrunk, so it always
+    /// applies to the original bounds.
+    pub fn aspect_ratio(mut self, ratio: f32) -> Limits {
+        self.aspect_ratio = Some((ratio, Size::ZERO));
+
+        self
+    }
+
     /// Shrinks the current [`Limits`] to account for the given padding.
     pub fn pad(&self, padding: Padding) -> Limits {
         self.shrink(Size::new(
@@ -141,22 +176,41 @@ impl Limits {
             (self.fill.height - size.height).max(0.0),
         );
 
-        Limits { min, max, fill }
+        let aspect_ratio = self.aspect_ratio.map(|(ratio, offset)| {
+            (
+                ratio,
+                Size::new(
+                    offset.width + size.width,
+                    offset.height + size.height,
+                ),
+            )
+        });
+
+        Limits {
+            min,
+            max,
+            fill,
+            aspect_ratio,
+            direction: self.direction,
+        }
     }
 
-    /// Removes the minimum width constraint for the current [`Limits`].
+    /// Removes the minimum size and aspect ratio constraints for the current
+    /// [`Limits`].
     pub fn loose(&self) -> Limits {
         Limits {
             min: Size::ZERO,
             max: self.max,
             fill: self.fill,
+            aspect_ratio: None,
+            direction: self.direction,
         }
     }
 
     /// Computes the resulting [`Size`] that fits the [`Limits`] given the
     /// intrinsic size of some content.
     pub fn resolve(&self, intrinsic_size: Size) -> Size {
-        Size::new(
+        let size = Size::new(
             intrinsic_size
                 .width
                 .min(self.max.width)
@@ -165,6 +219,22 @@ impl Limits {
                 .height
                 .min(self.max.height)
                 .max(self.fill.height),
-        )
+        );
+
+        match self.aspect_ratio {
+            Some((ratio, offset)) if ratio > 0.0 => {
+                let height = ((size.width + offset.width) / ratio
+                    - offset.height)
+                    .max(self.min.height)
+                    .min(self.max.height);
+
+                let width = ((height + offset.height) * ratio - offset.width)
+                    .max(self.min.width)
+                    .min(self.max.width);
+
+                Size::new(width, height)
+            }
+            _ => size,
+        }
     }
 }
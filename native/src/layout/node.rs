@@ -1,9 +1,11 @@
-use crate::{Alignment, Point, Rectangle, Size, Vector};
+use crate::{Alignment, Direction, Point, Rectangle, Size, Vector};
 
 /// The bounds of an element and its children.
 #[derive(Debug, Clone, Default)]
 pub struct Node {
     bounds: Rectangle,
+    baseline: Option<f32>,
+    direction: Direction,
     children: Vec<Node>,
 }
 
@@ -22,6 +24,8 @@ impl Node {
                 width: size.width,
                 height: size.height,
             },
+            baseline: None,
+            direction: Direction::LeftToRight,
             children,
         }
     }
@@ -36,6 +40,34 @@ impl Node {
         self.bounds
     }
 
+    /// Sets the baseline of the [`Node`], as the distance from its top to the
+    /// baseline of its first line of text.
+    pub fn with_baseline(mut self, baseline: f32) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Returns the baseline of the [`Node`], if it has one.
+    ///
+    /// The baseline is relative to the top of the [`Node`].
+    pub fn baseline(&self) -> Option<f32> {
+        self.baseline
+    }
+
+    /// Sets the layout [`Direction`] of the [`Node`].
+    ///
+    /// Widgets that need the [`Direction`] when handling events or drawing
+    /// store it in their [`Node`] during layout.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns the layout [`Direction`] of the [`Node`].
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
     /// Returns the children of the [`Node`].
     pub fn children(&self) -> &[Node] {
         &self.children
@@ -49,7 +81,7 @@ impl Node {
         space: Size,
     ) {
         match horizontal_alignment {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline => {}
             Alignment::Center => {
                 self.bounds.x += (space.width - self.bounds.width) / 2.0;
             }
@@ -62,7 +94,7 @@ impl Node {
         }
 
         match vertical_alignment {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline => {}
             Alignment::Center => {
                 self.bounds.y += (space.height - self.bounds.height) / 2.0;
             }
@@ -75,6 +107,12 @@ impl Node {
         }
     }
 
+    /// Mirrors the horizontal position of the [`Node`] inside the horizontal
+    /// span that starts at `x` and has the given `width`.
+    pub fn mirror(&mut self, x: f32, width: f32) {
+        self.bounds.x = 2.0 * x + width - self.bounds.x - self.bounds.width;
+    }
+
     /// Moves the [`Node`] to the given position.
     pub fn move_to(&mut self, position: Point) {
         self.bounds.x = position.x;
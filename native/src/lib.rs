@@ -70,8 +70,8 @@ mod debug;
 
 pub use iced_core::alignment;
 pub use iced_core::{
-    Alignment, Background, Color, Font, Length, Padding, Point, Rectangle,
-    Size, Vector,
+    Alignment, Background, Color, ContentFit, Direction, Font, Length, Padding,
+    Point, Rectangle, Size, Vector,
 };
 pub use iced_futures::{executor, futures};
 
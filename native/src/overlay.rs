@@ -87,4 +87,13 @@ where
     ) -> mouse::Interaction {
         mouse::Interaction::Idle
     }
+
+    /// Returns whether the [`Overlay`] is over the given cursor position,
+    /// hiding it from the widgets below.
+    ///
+    /// By default, it returns whether the bounds of the [`Overlay`] contain
+    /// the cursor.
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
 }
@@ -85,6 +85,11 @@ where
             .mouse_interaction(layout, cursor_position, viewport)
     }
 
+    /// Returns whether the [`Element`] is over the given cursor position.
+    pub fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.overlay.is_over(layout, cursor_position)
+    }
+
     /// Draws the [`Element`] and its children using the given [`Layout`].
     pub fn draw(
         &self,
@@ -165,6 +170,10 @@ where
             .mouse_interaction(layout, cursor_position, viewport)
     }
 
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.content.is_over(layout, cursor_position)
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -56,6 +56,10 @@ impl text::Renderer for Null {
         (0.0, 20.0)
     }
 
+    fn ascent(&self, _size: u16, _font: Font) -> f32 {
+        16.0
+    }
+
     fn hit_test(
         &self,
         _contents: &str,
@@ -69,4 +73,27 @@ impl text::Renderer for Null {
     }
 
     fn fill_text(&mut self, _text: Text<'_, Self::Font>) {}
+
+    fn measure_spans(
+        &self,
+        _spans: &[text::Span<'_, Self::Font>],
+        _bounds: Size,
+    ) -> (f32, f32) {
+        (0.0, 20.0)
+    }
+
+    fn span_regions(
+        &self,
+        spans: &[text::Span<'_, Self::Font>],
+        _bounds: Size,
+    ) -> Vec<Vec<Rectangle>> {
+        vec![Vec::new(); spans.len()]
+    }
+
+    fn fill_spans(
+        &mut self,
+        _spans: &[text::Span<'_, Self::Font>],
+        _bounds: Rectangle,
+    ) {
+    }
 }
@@ -1,13 +1,20 @@
+use crate::Point;
+
+use std::time::Instant;
+
 /// A connection to the state of a shell.
 ///
 /// A [`Widget`] can leverage a [`Shell`] to trigger changes in an application,
-/// like publishing messages or invalidating the current layout.
+/// like publishing messages, invalidating the current layout or requesting a
+/// redraw.
 ///
 /// [`Widget`]: crate::Widget
 #[derive(Debug)]
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     is_layout_invalid: bool,
+    redraw_request: Option<Instant>,
+    auto_scroll_request: Option<Point>,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -16,6 +23,8 @@ impl<'a, Message> Shell<'a, Message> {
         Self {
             messages,
             is_layout_invalid: false,
+            redraw_request: None,
+            auto_scroll_request: None,
         }
     }
 
@@ -41,6 +50,44 @@ impl<'a, Message> Shell<'a, Message> {
         self.is_layout_invalid = true;
     }
 
+    /// Requests a new frame to be drawn at the given [`Instant`].
+    ///
+    /// When the frame is drawn, widgets receive a
+    /// [`window::Event::RedrawRequested`] event first. Widgets that animate
+    /// should request their next frame when they receive it.
+    ///
+    /// [`window::Event::RedrawRequested`]: crate::window::Event::RedrawRequested
+    pub fn request_redraw(&mut self, at: Instant) {
+        self.redraw_request = Some(match self.redraw_request {
+            Some(requested) if requested < at => requested,
+            _ => at,
+        });
+    }
+
+    /// Returns the earliest redraw request of the [`Shell`], if any.
+    pub fn redraw_request(&self) -> Option<Instant> {
+        self.redraw_request
+    }
+
+    /// Requests the enclosing [`Scrollable`] to scroll automatically if the
+    /// given position is near its edges.
+    ///
+    /// Widgets that let users drag things around can use this to bring the
+    /// hidden parts of a [`Scrollable`] into view. The request only lasts for
+    /// the current event, so it should be repeated on every event while
+    /// dragging, including [`window::Event::RedrawRequested`].
+    ///
+    /// [`Scrollable`]: crate::widget::Scrollable
+    /// [`window::Event::RedrawRequested`]: crate::window::Event::RedrawRequested
+    pub fn request_auto_scroll(&mut self, position: Point) {
+        self.auto_scroll_request = Some(position);
+    }
+
+    /// Takes the auto-scroll request of the [`Shell`], if any.
+    pub fn take_auto_scroll_request(&mut self) -> Option<Point> {
+        self.auto_scroll_request.take()
+    }
+
     /// Merges the current [`Shell`] with another one by applying the given
     /// function to the messages of the latter.
     ///
@@ -50,5 +97,13 @@ impl<'a, Message> Shell<'a, Message> {
 
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
+
+        if let Some(at) = other.redraw_request {
+            self.request_redraw(at);
+        }
+
+        if let Some(position) = other.auto_scroll_request {
+            self.request_auto_scroll(position);
+        }
     }
 }
@@ -1,5 +1,5 @@
 //! Load and draw vector graphics.
-use crate::{Hasher, Rectangle};
+use crate::{Color, Hasher, Rectangle};
 
 use std::hash::{Hash, Hasher as _};
 use std::path::PathBuf;
@@ -84,5 +84,8 @@ pub trait Renderer: crate::Renderer {
     fn dimensions(&self, handle: &Handle) -> (u32, u32);
 
     /// Draws an SVG with the given [`Handle`] and inside the provided `bounds`.
-    fn draw(&mut self, handle: Handle, bounds: Rectangle);
+    ///
+    /// When a `color` is provided, the SVG is tinted with it, keeping only
+    /// its shape and transparency.
+    fn draw(&mut self, handle: Handle, color: Option<Color>, bounds: Rectangle);
 }
@@ -2,6 +2,8 @@
 use crate::alignment;
 use crate::{Color, Point, Rectangle, Size, Vector};
 
+use std::hash::Hash;
+
 /// A paragraph.
 #[derive(Debug, Clone, Copy)]
 pub struct Text<'a, Font> {
@@ -27,6 +29,24 @@ pub struct Text<'a, Font> {
     pub vertical_alignment: alignment::Vertical,
 }
 
+/// A span of a paragraph of rich text, with its own size, color and font.
+///
+/// The spans of a paragraph are laid out and wrapped together.
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a, Font> {
+    /// The content of the [`Span`].
+    pub content: &'a str,
+
+    /// The size of the [`Span`].
+    pub size: f32,
+
+    /// The color of the [`Span`].
+    pub color: Color,
+
+    /// The font of the [`Span`].
+    pub font: Font,
+}
+
 /// The result of hit testing on text.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Hit {
@@ -57,7 +77,7 @@ impl Hit {
 /// A renderer capable of measuring and drawing [`Text`].
 pub trait Renderer: crate::Renderer {
     /// The font type used.
-    type Font: Default + Clone;
+    type Font: Default + Clone + Hash;
 
     /// The icon font of the backend.
     const ICON_FONT: Self::Font;
@@ -92,6 +112,10 @@ pub trait Renderer: crate::Renderer {
         width
     }
 
+    /// Returns the ascent of the given font at the given size; that is, the
+    /// distance from the top of a line of text to its baseline.
+    fn ascent(&self, size: u16, font: Self::Font) -> f32;
+
     /// Tests whether the provided point is within the boundaries of text
     /// laid out with the given parameters, returning information about
     /// the nearest character.
@@ -111,4 +135,26 @@ pub trait Renderer: crate::Renderer {
 
     /// Draws the given [`Text`].
     fn fill_text(&mut self, text: Text<'_, Self::Font>);
+
+    /// Measures the given spans, laid out together as one paragraph in the
+    /// given bounds, and returns the minimum boundaries that can fit them.
+    fn measure_spans(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Returns the regions covered by each of the given spans, laid out
+    /// together as one paragraph in the given bounds.
+    ///
+    /// A span has one region for each line it is laid out in. The regions
+    /// are relative to the top-left corner of the paragraph.
+    fn span_regions(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+    ) -> Vec<Vec<Rectangle>>;
+
+    /// Draws the given spans as one paragraph inside the given bounds.
+    fn fill_spans(&mut self, spans: &[Span<'_, Self::Font>], bounds: Rectangle);
 }
@@ -6,6 +6,7 @@ use crate::renderer;
 use crate::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
 
 use std::hash::Hasher;
+use std::time::Instant;
 
 /// A set of interactive graphical elements with a specific [`Layout`].
 ///
@@ -25,6 +26,7 @@ pub struct UserInterface<'a, Message, Renderer> {
     base: Layer,
     overlay: Option<Layer>,
     bounds: Size,
+    redraw_request: Option<Instant>,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -123,6 +125,7 @@ where
             base,
             overlay,
             bounds,
+            redraw_request: None,
         }
     }
 
@@ -200,6 +203,8 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> Vec<event::Status> {
+        let mut redraw_request = None;
+
         let (base_cursor, overlay_statuses) = if let Some(mut overlay) =
             self.root.overlay(Layout::new(&self.base.layout))
         {
@@ -227,6 +232,10 @@ where
                         &mut shell,
                     );
 
+                    if let Some(at) = shell.redraw_request() {
+                        redraw_request = earliest(redraw_request, at);
+                    }
+
                     shell.with_invalid_layout(|| {
                         layer = Self::overlay_layer(
                             None,
@@ -240,7 +249,8 @@ where
                 })
                 .collect();
 
-            let base_cursor = if layer.layout.bounds().contains(cursor_position)
+            let base_cursor = if overlay
+                .is_over(Layout::new(&layer.layout), cursor_position)
             {
                 // TODO: Type-safe cursor availability
                 Point::new(-1.0, -1.0)
@@ -255,7 +265,7 @@ where
             (cursor_position, vec![event::Status::Ignored; events.len()])
         };
 
-        events
+        let statuses = events
             .iter()
             .cloned()
             .zip(overlay_statuses.into_iter())
@@ -271,6 +281,10 @@ where
                     &mut shell,
                 );
 
+                if let Some(at) = shell.redraw_request() {
+                    redraw_request = earliest(redraw_request, at);
+                }
+
                 shell.with_invalid_layout(|| {
                     let hash = {
                         let hasher = &mut crate::Hasher::default();
@@ -290,7 +304,26 @@ where
 
                 event_status.merge(overlay_status)
             })
-            .collect()
+            .collect();
+
+        self.redraw_request = redraw_request;
+
+        statuses
+    }
+
+    /// Returns the earliest redraw requested by the widgets of the
+    /// [`UserInterface`] during the last call to [`update`], if any.
+    ///
+    /// A shell should draw a new frame at the returned [`Instant`], sending
+    /// a [`window::Event::RedrawRequested`] event to the [`UserInterface`]
+    /// before drawing it. As every call to [`update`] replaces the request, a
+    /// shell calling it more than once per frame should keep the earliest of
+    /// the requests.
+    ///
+    /// [`update`]: Self::update
+    /// [`window::Event::RedrawRequested`]: crate::window::Event::RedrawRequested
+    pub fn redraw_request(&self) -> Option<Instant> {
+        self.redraw_request
     }
 
     /// Draws the [`UserInterface`] with the provided [`Renderer`].
@@ -375,7 +408,7 @@ where
 
         let viewport = Rectangle::with_size(self.bounds);
 
-        if let Some(mut overlay) =
+        let base_cursor = if let Some(mut overlay) =
             self.root.overlay(Layout::new(&self.base.layout))
         {
             let layer = Self::overlay_layer(
@@ -385,34 +418,29 @@ where
                 renderer,
             );
 
-            self.overlay = Some(layer);
-        };
-
-        if let Some(layer) = &self.overlay {
-            let base_cursor = if layer.layout.bounds().contains(cursor_position)
+            let base_cursor = if overlay
+                .is_over(Layout::new(&layer.layout), cursor_position)
             {
                 Point::new(-1.0, -1.0)
             } else {
                 cursor_position
             };
 
-            self.root.widget.draw(
-                renderer,
-                &renderer::Style::default(),
-                Layout::new(&self.base.layout),
-                base_cursor,
-                &viewport,
-            );
+            self.overlay = Some(layer);
+
+            base_cursor
         } else {
-            self.root.widget.draw(
-                renderer,
-                &renderer::Style::default(),
-                Layout::new(&self.base.layout),
-                cursor_position,
-                &viewport,
-            );
+            cursor_position
         };
 
+        self.root.widget.draw(
+            renderer,
+            &renderer::Style::default(),
+            Layout::new(&self.base.layout),
+            base_cursor,
+            &viewport,
+        );
+
         let base_interaction = self.root.widget.mouse_interaction(
             Layout::new(&self.base.layout),
             cursor_position,
@@ -452,7 +480,9 @@ where
                         );
                     });
 
-                    if overlay_bounds.contains(cursor_position) {
+                    if overlay
+                        .is_over(Layout::new(&layer.layout), cursor_position)
+                    {
                         overlay_interaction
                     } else {
                         base_interaction
@@ -548,3 +578,10 @@ impl Default for Cache {
         Cache::new()
     }
 }
+
+fn earliest(current: Option<Instant>, at: Instant) -> Option<Instant> {
+    Some(match current {
+        Some(current) if current < at => current,
+        _ => at,
+    })
+}
@@ -2,20 +2,30 @@
 //!
 //! A [`Button`] has some local [`State`].
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::touch;
+use crate::window;
 use crate::{
     Background, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
     Point, Rectangle, Shell, Vector, Widget,
 };
 
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
 pub use iced_style::button::{Style, StyleSheet};
 
+/// The time a finger needs to be held on a [`Button`] to produce a long press.
+const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+/// The maximum time between two presses of a [`Button`] to produce a double
+/// press.
+const DOUBLE_PRESS_DURATION: Duration = Duration::from_millis(300);
+
 /// A generic widget that produces a message when pressed.
 ///
 /// ```
@@ -56,11 +66,42 @@ pub use iced_style::button::{Style, StyleSheet};
 ///     disabled_button(state).on_press(Message::ButtonPressed)
 /// }
 /// ```
+///
+/// A [`Button`] can also react to other kinds of presses, like right clicks
+/// or double clicks, and to the keyboard modifiers held during a press:
+///
+/// ```
+/// # use iced_native::keyboard;
+/// # use iced_native::widget::{button, Text};
+/// #
+/// # type Button<'a, Message> =
+/// #     iced_native::widget::Button<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     ItemSelected(usize, keyboard::Modifiers),
+///     ItemOpened(usize),
+///     ContextMenuRequested(usize),
+/// }
+///
+/// fn item(state: &mut button::State, index: usize) -> Button<'_, Message> {
+///     Button::new(state, Text::new("Item"))
+///         .on_press_with_modifiers(move |modifiers| {
+///             Message::ItemSelected(index, modifiers)
+///         })
+///         .on_double_press(Message::ItemOpened(index))
+///         .on_right_press(Message::ContextMenuRequested(index))
+/// }
+/// ```
 #[allow(missing_debug_implementations)]
 pub struct Button<'a, Message, Renderer> {
     state: &'a mut State,
     content: Element<'a, Message, Renderer>,
-    on_press: Option<Message>,
+    on_press: Option<OnPress<'a, Message>>,
+    on_right_press: Option<Message>,
+    on_middle_press: Option<Message>,
+    on_double_press: Option<Message>,
+    on_long_press: Option<Message>,
     width: Length,
     height: Length,
     min_width: u32,
@@ -84,6 +125,10 @@ where
             state,
             content: content.into(),
             on_press: None,
+            on_right_press: None,
+            on_middle_press: None,
+            on_double_press: None,
+            on_long_press: None,
             width: Length::Shrink,
             height: Length::Shrink,
             min_width: 0,
@@ -124,9 +169,53 @@ where
     }
 
     /// Sets the message that will be produced when the [`Button`] is pressed.
-    /// If on_press isn't set, button will be disabled.
+    /// If no press handler is set, button will be disabled.
     pub fn on_press(mut self, msg: Message) -> Self {
-        self.on_press = Some(msg);
+        self.on_press = Some(OnPress::Direct(msg));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed,
+    /// given the keyboard modifiers that were held during the press.
+    ///
+    /// It replaces any message set with [`Button::on_press`].
+    pub fn on_press_with_modifiers(
+        mut self,
+        f: impl Fn(keyboard::Modifiers) -> Message + 'a,
+    ) -> Self {
+        self.on_press = Some(OnPress::WithModifiers(Box::new(f)));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the right mouse button.
+    pub fn on_right_press(mut self, msg: Message) -> Self {
+        self.on_right_press = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the middle mouse button.
+    pub fn on_middle_press(mut self, msg: Message) -> Self {
+        self.on_middle_press = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// twice in a row.
+    ///
+    /// The message is produced after the one of the second press.
+    pub fn on_double_press(mut self, msg: Message) -> Self {
+        self.on_double_press = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when a finger is held on the
+    /// [`Button`].
+    ///
+    /// A long press does not produce the message of a regular press.
+    pub fn on_long_press(mut self, msg: Message) -> Self {
+        self.on_long_press = Some(msg);
         self
     }
 
@@ -138,12 +227,73 @@ where
         self.style_sheet = style_sheet.into();
         self
     }
+
+    fn is_disabled(&self) -> bool {
+        self.on_press.is_none()
+            && self.on_right_press.is_none()
+            && self.on_middle_press.is_none()
+            && self.on_double_press.is_none()
+            && self.on_long_press.is_none()
+    }
+
+    /// Returns the message to produce when the [`Button`] is released after
+    /// the given [`Press`], if any.
+    fn release(&self, press: Press) -> Option<Message> {
+        match press {
+            Press::Mouse(mouse::Button::Left) | Press::Touch { .. } => {
+                match self.on_press.as_ref()? {
+                    OnPress::Direct(message) => Some(message.clone()),
+                    OnPress::WithModifiers(f) => Some(f(self.state.modifiers)),
+                }
+            }
+            Press::Mouse(mouse::Button::Right) => self.on_right_press.clone(),
+            Press::Mouse(mouse::Button::Middle) => self.on_middle_press.clone(),
+            Press::Mouse(_) => None,
+        }
+    }
+
+    fn publish_release(&self, press: Press, shell: &mut Shell<'_, Message>) {
+        if let Press::Touch { is_long: true, .. } = press {
+            return;
+        }
+
+        if let Some(message) = self.release(press) {
+            shell.publish(message);
+        }
+
+        let is_double = matches!(
+            self.state.last_tap,
+            Some(Tap {
+                is_double: true,
+                ..
+            })
+        );
+
+        let is_primary = matches!(
+            press,
+            Press::Mouse(mouse::Button::Left) | Press::Touch { .. }
+        );
+
+        if is_double && is_primary {
+            if let Some(on_double_press) = self.on_double_press.clone() {
+                shell.publish(on_double_press);
+            }
+        }
+    }
+}
+
+/// The message produced by a regular press of a [`Button`].
+enum OnPress<'a, Message> {
+    Direct(Message),
+    WithModifiers(Box<dyn Fn(keyboard::Modifiers) -> Message + 'a>),
 }
 
 /// The local state of a [`Button`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
-    is_pressed: bool,
+    press: Option<Press>,
+    last_tap: Option<Tap>,
+    modifiers: keyboard::Modifiers,
 }
 
 impl State {
@@ -151,6 +301,59 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Button`] is currently being pressed.
+    pub fn is_pressed(&self) -> bool {
+        self.press.is_some()
+    }
+}
+
+/// The last primary press of a [`Button`], kept to detect double presses.
+///
+/// Its position is rounded, so the [`State`] of a [`Button`] can be [`Eq`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Tap {
+    x: i32,
+    y: i32,
+    time: Instant,
+    is_double: bool,
+}
+
+impl Tap {
+    /// Creates a new [`Tap`] at the given position, following the previous
+    /// [`Tap`], if any.
+    fn new(position: Point, previous: Option<Tap>) -> Tap {
+        let x = position.x.round() as i32;
+        let y = position.y.round() as i32;
+        let time = Instant::now();
+
+        // Like mouse clicks, a third consecutive press is not a double one
+        let is_double = previous.is_some_and(|previous| {
+            !previous.is_double
+                && previous.x == x
+                && previous.y == y
+                && time.saturating_duration_since(previous.time)
+                    <= DOUBLE_PRESS_DURATION
+        });
+
+        Tap {
+            x,
+            y,
+            time,
+            is_double,
+        }
+    }
+}
+
+/// An on-going press of a [`Button`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Press {
+    Mouse(mouse::Button),
+    Touch {
+        id: touch::Finger,
+        started_at: Instant,
+        is_long: bool,
+    },
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -186,8 +389,16 @@ where
         ));
 
         let size = limits.resolve(content.size()).pad(self.padding);
+        let baseline = content
+            .baseline()
+            .map(|baseline| f32::from(self.padding.top) + baseline);
+
+        let node = layout::Node::with_children(size, vec![content]);
 
-        layout::Node::with_children(size, vec![content])
+        match baseline {
+            Some(baseline) => node.with_baseline(baseline),
+            None => node,
+        }
     }
 
     fn on_event(
@@ -210,29 +421,73 @@ where
             return event::Status::Captured;
         }
 
+        let bounds = layout.bounds();
+
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if self.on_press.is_some() {
-                    let bounds = layout.bounds();
+            Event::Mouse(mouse::Event::ButtonPressed(button)) => {
+                let has_handler = match button {
+                    mouse::Button::Left => {
+                        self.on_press.is_some()
+                            || self.on_double_press.is_some()
+                    }
+                    mouse::Button::Right => self.on_right_press.is_some(),
+                    mouse::Button::Middle => self.on_middle_press.is_some(),
+                    mouse::Button::Other(_) => false,
+                };
+
+                if has_handler && bounds.contains(cursor_position) {
+                    if button == mouse::Button::Left {
+                        self.state.last_tap = Some(Tap::new(
+                            cursor_position,
+                            self.state.last_tap,
+                        ));
+                    }
 
-                    if bounds.contains(cursor_position) {
-                        self.state.is_pressed = true;
+                    self.state.press = Some(Press::Mouse(button));
 
-                        return event::Status::Captured;
-                    }
+                    return event::Status::Captured;
                 }
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. }) => {
-                if let Some(on_press) = self.on_press.clone() {
-                    let bounds = layout.bounds();
+            Event::Touch(touch::Event::FingerPressed { id, .. })
+                if !self.is_disabled() && bounds.contains(cursor_position) =>
+            {
+                let started_at = Instant::now();
+
+                if self.on_long_press.is_some() {
+                    shell.request_redraw(started_at + LONG_PRESS_DURATION);
+                }
 
-                    if self.state.is_pressed {
-                        self.state.is_pressed = false;
+                self.state.last_tap =
+                    Some(Tap::new(cursor_position, self.state.last_tap));
+
+                self.state.press = Some(Press::Touch {
+                    id,
+                    started_at,
+                    is_long: false,
+                });
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(button))
+                if self.state.press == Some(Press::Mouse(button)) =>
+            {
+                self.state.press = None;
+
+                if bounds.contains(cursor_position) {
+                    self.publish_release(Press::Mouse(button), shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerLifted { id, .. }) => {
+                if let Some(press @ Press::Touch { id: pressed, .. }) =
+                    self.state.press
+                {
+                    if pressed == id {
+                        self.state.press = None;
 
                         if bounds.contains(cursor_position) {
-                            shell.publish(on_press);
+                            self.publish_release(press, shell);
                         }
 
                         return event::Status::Captured;
@@ -240,7 +495,32 @@ where
                 }
             }
             Event::Touch(touch::Event::FingerLost { .. }) => {
-                self.state.is_pressed = false;
+                self.state.press = None;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.state.modifiers = modifiers;
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(Press::Touch {
+                    started_at,
+                    is_long: is_long @ false,
+                    ..
+                }) = &mut self.state.press
+                {
+                    // The requested frame may be dropped before the press
+                    // becomes long, so we keep requesting it until then.
+                    if let Some(on_long_press) = self.on_long_press.clone() {
+                        let long_press_at = *started_at + LONG_PRESS_DURATION;
+
+                        if now < long_press_at {
+                            shell.request_redraw(long_press_at);
+                        } else if bounds.contains(cursor_position) {
+                            *is_long = true;
+
+                            shell.publish(on_long_press);
+                        }
+                    }
+                }
             }
             _ => {}
         }
@@ -255,7 +535,7 @@ where
         _viewport: &Rectangle,
     ) -> mouse::Interaction {
         let is_mouse_over = layout.bounds().contains(cursor_position);
-        let is_disabled = self.on_press.is_none();
+        let is_disabled = self.is_disabled();
 
         if is_mouse_over && !is_disabled {
             mouse::Interaction::Pointer
@@ -276,12 +556,12 @@ where
         let content_layout = layout.children().next().unwrap();
 
         let is_mouse_over = bounds.contains(cursor_position);
-        let is_disabled = self.on_press.is_none();
+        let is_disabled = self.is_disabled();
 
         let styling = if is_disabled {
             self.style_sheet.disabled()
         } else if is_mouse_over {
-            if self.state.is_pressed {
+            if self.state.is_pressed() {
                 self.style_sheet.pressed()
             } else {
                 self.style_sheet.hovered()
@@ -18,6 +18,11 @@ pub use iced_style::checkbox::{Style, StyleSheet};
 
 /// A box that can be checked.
 ///
+/// A [`Checkbox`] can also be in an [`Indeterminate`] state, like a "select
+/// all" checkbox over a partially selected list. Toggling it checks it.
+///
+/// [`Indeterminate`]: CheckState::Indeterminate
+///
 /// # Example
 ///
 /// ```
@@ -35,8 +40,9 @@ pub use iced_style::checkbox::{Style, StyleSheet};
 /// ![Checkbox drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/checkbox.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Checkbox<'a, Message, Renderer: text::Renderer> {
-    is_checked: bool,
+    state: CheckState,
     on_toggle: Box<dyn Fn(bool) -> Message>,
+    is_disabled: bool,
     label: String,
     width: Length,
     size: u16,
@@ -57,18 +63,24 @@ impl<'a, Message, Renderer: text::Renderer> Checkbox<'a, Message, Renderer> {
     /// Creates a new [`Checkbox`].
     ///
     /// It expects:
-    ///   * a boolean describing whether the [`Checkbox`] is checked or not
+    ///   * the [`CheckState`] of the [`Checkbox`], or a boolean describing
+    ///     whether it is checked or not
     ///   * the label of the [`Checkbox`]
     ///   * a function that will be called when the [`Checkbox`] is toggled. It
     ///     will receive the new state of the [`Checkbox`] and must produce a
     ///     `Message`.
-    pub fn new<F>(is_checked: bool, label: impl Into<String>, f: F) -> Self
+    pub fn new<F>(
+        state: impl Into<CheckState>,
+        label: impl Into<String>,
+        f: F,
+    ) -> Self
     where
         F: 'static + Fn(bool) -> Message,
     {
         Checkbox {
-            is_checked,
+            state: state.into(),
             on_toggle: Box::new(f),
+            is_disabled: false,
             label: label.into(),
             width: Length::Shrink,
             size: Self::DEFAULT_SIZE,
@@ -118,6 +130,14 @@ impl<'a, Message, Renderer: text::Renderer> Checkbox<'a, Message, Renderer> {
         self
     }
 
+    /// Sets whether the [`Checkbox`] is disabled.
+    ///
+    /// A disabled [`Checkbox`] cannot be toggled.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
     /// Sets the style of the [`Checkbox`].
     pub fn style(
         mut self,
@@ -178,8 +198,10 @@ where
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let mouse_over = layout.bounds().contains(cursor_position);
 
-                if mouse_over {
-                    shell.publish((self.on_toggle)(!self.is_checked));
+                if mouse_over && !self.is_disabled {
+                    shell.publish((self.on_toggle)(
+                        self.state != CheckState::Checked,
+                    ));
 
                     return event::Status::Captured;
                 }
@@ -196,7 +218,7 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) -> mouse::Interaction {
-        if layout.bounds().contains(cursor_position) {
+        if layout.bounds().contains(cursor_position) && !self.is_disabled {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
@@ -216,10 +238,20 @@ where
 
         let mut children = layout.children();
 
-        let custom_style = if is_mouse_over {
-            self.style_sheet.hovered(self.is_checked)
-        } else {
-            self.style_sheet.active(self.is_checked)
+        let custom_style = match self.state {
+            _ if self.is_disabled => self
+                .style_sheet
+                .disabled(self.state != CheckState::Unchecked),
+            CheckState::Indeterminate if is_mouse_over => {
+                self.style_sheet.hovered_indeterminate()
+            }
+            CheckState::Indeterminate => {
+                self.style_sheet.active_indeterminate()
+            }
+            state if is_mouse_over => {
+                self.style_sheet.hovered(state == CheckState::Checked)
+            }
+            state => self.style_sheet.active(state == CheckState::Checked),
         };
 
         {
@@ -236,8 +268,8 @@ where
                 custom_style.background,
             );
 
-            if self.is_checked {
-                renderer.fill_text(text::Text {
+            match self.state {
+                CheckState::Checked => renderer.fill_text(text::Text {
                     content: &Renderer::CHECKMARK_ICON.to_string(),
                     font: Renderer::ICON_FONT,
                     size: bounds.height * 0.7,
@@ -249,7 +281,27 @@ where
                     color: custom_style.checkmark_color,
                     horizontal_alignment: alignment::Horizontal::Center,
                     vertical_alignment: alignment::Vertical::Center,
-                });
+                }),
+                CheckState::Indeterminate => {
+                    let width = (bounds.width * 0.5).round();
+                    let height = (bounds.height * 0.15).round().max(1.0);
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.center_x() - width / 2.0,
+                                y: bounds.center_y() - height / 2.0,
+                                width,
+                                height,
+                            },
+                            border_radius: height / 2.0,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        custom_style.checkmark_color,
+                    );
+                }
+                CheckState::Unchecked => {}
             }
         }
 
@@ -278,6 +330,30 @@ where
     }
 }
 
+/// The state of a [`Checkbox`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckState {
+    /// The [`Checkbox`] is checked.
+    Checked,
+
+    /// The [`Checkbox`] is not checked.
+    Unchecked,
+
+    /// The [`Checkbox`] is neither checked nor unchecked, like when only some
+    /// of the options it stands for are checked.
+    Indeterminate,
+}
+
+impl From<bool> for CheckState {
+    fn from(is_checked: bool) -> Self {
+        if is_checked {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        }
+    }
+}
+
 impl<'a, Message, Renderer> From<Checkbox<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
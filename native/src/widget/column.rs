@@ -1,6 +1,7 @@
 //! Distribute content vertically.
 use std::hash::Hash;
 
+use crate::alignment::Justify;
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
@@ -22,7 +23,12 @@ pub struct Column<'a, Message, Renderer> {
     height: Length,
     max_width: u32,
     max_height: u32,
+    min_width: u32,
+    min_height: u32,
+    aspect_ratio: Option<f32>,
     align_items: Alignment,
+    justify_content: Justify,
+    wrap: bool,
     children: Vec<Element<'a, Message, Renderer>>,
 }
 
@@ -43,7 +49,12 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
             height: Length::Shrink,
             max_width: u32::MAX,
             max_height: u32::MAX,
+            min_width: 0,
+            min_height: 0,
+            aspect_ratio: None,
             align_items: Alignment::Start,
+            justify_content: Justify::Start,
+            wrap: false,
             children,
         }
     }
@@ -88,12 +99,47 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the minimum width of the [`Column`].
+    pub fn min_width(mut self, min_width: u32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the minimum height of the [`Column`].
+    pub fn min_height(mut self, min_height: u32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
+    /// Sets the aspect ratio of the [`Column`], as its width divided by its
+    /// height.
+    ///
+    /// The [`Column`] keeps this ratio whenever its constraints allow it.
+    pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Column`] .
     pub fn align_items(mut self, align: Alignment) -> Self {
         self.align_items = align;
         self
     }
 
+    /// Sets how the free space is distributed between the contents of the
+    /// [`Column`].
+    pub fn justify_content(mut self, justify: Justify) -> Self {
+        self.justify_content = justify;
+        self
+    }
+
+    /// Makes the contents of the [`Column`] flow onto new columns when they do
+    /// not fit.
+    pub fn wrap(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
     /// Adds an element to the [`Column`].
     pub fn push<E>(mut self, child: E) -> Self
     where
@@ -125,16 +171,27 @@ where
         let limits = limits
             .max_width(self.max_width)
             .max_height(self.max_height)
+            .min_width(self.min_width)
+            .min_height(self.min_height)
             .width(self.width)
             .height(self.height);
 
-        layout::flex::resolve(
+        let limits = match self.aspect_ratio {
+            Some(ratio) => limits.aspect_ratio(ratio),
+            None => limits,
+        };
+
+        layout::flex::resolve_with_options(
             layout::flex::Axis::Vertical,
             renderer,
             &limits,
             self.padding,
-            self.spacing as f32,
-            self.align_items,
+            layout::flex::Options {
+                spacing: self.spacing as f32,
+                align_items: self.align_items,
+                justify_content: self.justify_content,
+                wrap: self.wrap,
+            },
             &self.children,
         )
     }
@@ -205,7 +262,12 @@ where
         self.height.hash(state);
         self.max_width.hash(state);
         self.max_height.hash(state);
+        self.min_width.hash(state);
+        self.min_height.hash(state);
+        self.aspect_ratio.map(f32::to_bits).hash(state);
         self.align_items.hash(state);
+        self.justify_content.hash(state);
+        self.wrap.hash(state);
         self.spacing.hash(state);
         self.padding.hash(state);
 
@@ -8,8 +8,8 @@ use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::{
-    Background, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
-    Point, Rectangle, Shell, Widget,
+    Background, Clipboard, Color, Direction, Element, Hasher, Layout, Length,
+    Padding, Point, Rectangle, Shell, Widget,
 };
 
 use std::u32;
@@ -26,8 +26,12 @@ pub struct Container<'a, Message, Renderer> {
     height: Length,
     max_width: u32,
     max_height: u32,
+    min_width: u32,
+    min_height: u32,
+    aspect_ratio: Option<f32>,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    direction: Option<Direction>,
     style_sheet: Box<dyn StyleSheet + 'a>,
     content: Element<'a, Message, Renderer>,
 }
@@ -47,8 +51,12 @@ where
             height: Length::Shrink,
             max_width: u32::MAX,
             max_height: u32::MAX,
+            min_width: 0,
+            min_height: 0,
+            aspect_ratio: None,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            direction: None,
             style_sheet: Default::default(),
             content: content.into(),
         }
@@ -84,7 +92,31 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Container`].
+    pub fn min_width(mut self, min_width: u32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the minimum height of the [`Container`].
+    pub fn min_height(mut self, min_height: u32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
+    /// Sets the aspect ratio of the [`Container`], as its width divided by its
+    /// height.
+    ///
+    /// The [`Container`] keeps this ratio whenever its constraints allow it.
+    pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+
     /// Sets the content alignment for the horizontal axis of the [`Container`].
+    ///
+    /// In a right-to-left layout, the alignment is mirrored, so the contents
+    /// aligned to the `Left` start at the right of the [`Container`].
     pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
         self.horizontal_alignment = alignment;
         self
@@ -108,6 +140,15 @@ where
         self
     }
 
+    /// Sets the layout [`Direction`] of the contents of the [`Container`].
+    ///
+    /// The [`Direction`] is inherited by every widget inside the [`Container`]
+    /// that does not set its own. By default, it is inherited from the parent.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
     /// Sets the style of the [`Container`].
     pub fn style(
         mut self,
@@ -140,9 +181,21 @@ where
             .loose()
             .max_width(self.max_width)
             .max_height(self.max_height)
+            .min_width(self.min_width)
+            .min_height(self.min_height)
             .width(self.width)
-            .height(self.height)
-            .pad(self.padding);
+            .height(self.height);
+
+        let limits = match self.aspect_ratio {
+            Some(ratio) => limits.aspect_ratio(ratio),
+            None => limits,
+        }
+        .pad(self.padding);
+
+        let limits = match self.direction {
+            Some(direction) => limits.with_direction(direction),
+            None => limits,
+        };
 
         let mut content = self.content.layout(renderer, &limits.loose());
         let size = limits.resolve(content.size());
@@ -157,7 +210,21 @@ where
             size,
         );
 
-        layout::Node::with_children(size.pad(self.padding), vec![content])
+        if limits.direction().is_right_to_left() {
+            content.mirror(self.padding.left.into(), size.width);
+        }
+
+        let baseline = content
+            .baseline()
+            .map(|baseline| content.bounds().y + baseline);
+
+        let node =
+            layout::Node::with_children(size.pad(self.padding), vec![content]);
+
+        match baseline {
+            Some(baseline) => node.with_baseline(baseline),
+            None => node,
+        }
     }
 
     fn on_event(
@@ -226,8 +293,12 @@ where
         self.height.hash(state);
         self.max_width.hash(state);
         self.max_height.hash(state);
+        self.min_width.hash(state);
+        self.min_height.hash(state);
+        self.aspect_ratio.map(f32::to_bits).hash(state);
         self.horizontal_alignment.hash(state);
         self.vertical_alignment.hash(state);
+        self.direction.hash(state);
 
         self.content.hash_layout(state);
     }
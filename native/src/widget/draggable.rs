@@ -0,0 +1,385 @@
+//! Drag content and drop it on a [`DropTarget`].
+//!
+//! [`DropTarget`]: crate::widget::DropTarget
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+use std::hash::Hash;
+
+/// The distance the cursor needs to travel with the left mouse button pressed
+/// before a drag starts.
+const DRAG_DEADBAND_DISTANCE: f32 = 5.0;
+
+/// A wrapper that lets its content be dragged around, carrying a payload.
+///
+/// While dragged, a ghost of the content follows the cursor in an overlay.
+/// Pressing Escape cancels the drag.
+///
+/// A [`Draggable`] only tracks the drag itself. Your application should keep
+/// the payload produced by [`on_drag`](Self::on_drag) and give it to the
+/// [`DropTarget`] widgets that may accept it, until the drag ends.
+///
+/// [`DropTarget`]: crate::widget::DropTarget
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{draggable, Text};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type Draggable<'a, T, Message> =
+/// #     iced_native::widget::Draggable<'a, T, Message, Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CardDragged(usize),
+///     DragEnded,
+/// }
+///
+/// let mut state = draggable::State::new();
+/// let card = Draggable::new(&mut state, 42, Text::new("Write the docs"))
+///     .on_drag(Message::CardDragged)
+///     .on_end(Message::DragEnded);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Draggable<'a, T, Message, Renderer> {
+    state: &'a mut State,
+    payload: T,
+    content: Element<'a, Message, Renderer>,
+    on_drag: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_end: Option<Message>,
+}
+
+impl<'a, T, Message, Renderer> Draggable<'a, T, Message, Renderer> {
+    /// Creates a new [`Draggable`] with the given [`State`], payload and
+    /// content.
+    pub fn new(
+        state: &'a mut State,
+        payload: T,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Draggable {
+            state,
+            payload,
+            content: content.into(),
+            on_drag: None,
+            on_end: None,
+        }
+    }
+
+    /// Sets the function that will be called when a drag of the
+    /// [`Draggable`] starts.
+    ///
+    /// It receives the payload of the [`Draggable`].
+    pub fn on_drag(mut self, f: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when a drag of the
+    /// [`Draggable`] ends, whether it was dropped or cancelled.
+    pub fn on_end(mut self, message: Message) -> Self {
+        self.on_end = Some(message);
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for Draggable<'a, T, Message, Renderer>
+where
+    T: Clone,
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if status == event::Status::Ignored
+                    && self.state.drag.is_none()
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                self.state.pressed_at = Some(cursor_position);
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = &mut self.state.drag {
+                    drag.position = cursor_position;
+
+                    return event::Status::Captured;
+                }
+
+                if let Some(pressed_at) = self.state.pressed_at {
+                    if pressed_at.distance(cursor_position)
+                        > DRAG_DEADBAND_DISTANCE
+                    {
+                        let bounds = layout.bounds();
+
+                        self.state.pressed_at = None;
+                        self.state.drag = Some(Drag {
+                            grab: pressed_at - bounds.position(),
+                            position: cursor_position,
+                            size: bounds.size(),
+                        });
+
+                        if let Some(on_drag) = &self.on_drag {
+                            shell.publish(on_drag(self.payload.clone()));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.pressed_at = None;
+
+                if self.state.drag.take().is_some() {
+                    if let Some(on_end) = self.on_end.clone() {
+                        shell.publish(on_end);
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) if self.state.drag.is_some() => {
+                self.state.drag = None;
+
+                if let Some(on_end) = self.on_end.clone() {
+                    shell.publish(on_end);
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        if self.state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction =
+            self.content
+                .mouse_interaction(layout, cursor_position, viewport);
+
+        if interaction == mouse::Interaction::Idle
+            && layout.bounds().contains(cursor_position)
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        match self.state.drag {
+            Some(drag) => Some(overlay::Element::new(
+                drag.position - drag.grab,
+                Box::new(Ghost {
+                    content: &self.content,
+                    size: drag.size,
+                }),
+            )),
+            None => self.content.overlay(layout),
+        }
+    }
+}
+
+/// The local state of a [`Draggable`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    pressed_at: Option<Point>,
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    grab: Vector,
+    position: Point,
+    size: Size,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the [`Draggable`] is currently being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+}
+
+/// The ghost of a [`Draggable`] that follows the cursor.
+struct Ghost<'a, 'b, Message, Renderer> {
+    content: &'b Element<'a, Message, Renderer>,
+    size: Size,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Ghost<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        _bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .layout(renderer, &layout::Limits::new(Size::ZERO, self.size));
+
+        let mut node =
+            layout::Node::with_children(content.size(), vec![content]);
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        mouse::Interaction::Idle
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _cursor_position: Point) -> bool {
+        // The widgets below keep receiving the cursor while the ghost is
+        // dragged over them, so they can accept the drop.
+        false
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let layout = layout.children().next().unwrap();
+
+        self.content.draw(
+            renderer,
+            style,
+            layout,
+            cursor_position,
+            &layout.bounds(),
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        (self.size.width as u32).hash(state);
+        (self.size.height as u32).hash(state);
+        self.content.hash_layout(state);
+    }
+}
+
+impl<'a, T, Message, Renderer> From<Draggable<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        draggable: Draggable<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(draggable)
+    }
+}
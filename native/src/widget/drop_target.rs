@@ -0,0 +1,261 @@
+//! Accept content dropped from a [`Draggable`].
+//!
+//! [`Draggable`]: crate::widget::Draggable
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::{
+    Background, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
+    Point, Rectangle, Shell, Widget,
+};
+
+use std::hash::Hash;
+
+pub use iced_style::drop_target::{Style, StyleSheet};
+
+/// A wrapper that accepts the payload of a [`Draggable`] dropped on it.
+///
+/// A [`DropTarget`] is given the payload being dragged, if any. It is
+/// highlighted while it can accept that payload, and produces a message
+/// carrying it when it is dropped on the [`DropTarget`].
+///
+/// [`Draggable`]: crate::widget::Draggable
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::Text;
+/// # use iced_native::renderer::Null;
+/// #
+/// # type DropTarget<'a, T, Message> =
+/// #     iced_native::widget::DropTarget<'a, T, Message, Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CardMoved { card: usize, column: usize },
+/// }
+///
+/// let dragging: Option<usize> = Some(42);
+///
+/// let done = DropTarget::new(Text::new("Done"), dragging, |card| {
+///     Message::CardMoved { card, column: 2 }
+/// })
+/// .padding(10);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<'a, T, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    dragging: Option<T>,
+    on_drop: Box<dyn Fn(T) -> Message + 'a>,
+    accepts: Box<dyn Fn(&T) -> bool + 'a>,
+    padding: Padding,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, T, Message, Renderer> DropTarget<'a, T, Message, Renderer> {
+    /// Creates a new [`DropTarget`] with the given content.
+    ///
+    /// It expects:
+    ///   * the content of the [`DropTarget`]
+    ///   * the payload currently being dragged, if any
+    ///   * a function that produces a message when the payload is dropped on
+    ///     the [`DropTarget`]
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        dragging: Option<T>,
+        on_drop: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        DropTarget {
+            content: content.into(),
+            dragging,
+            on_drop: Box::new(on_drop),
+            accepts: Box::new(|_| true),
+            padding: Padding::ZERO,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the function that decides whether the [`DropTarget`] accepts a
+    /// payload.
+    ///
+    /// By default, every payload is accepted.
+    pub fn accepts(mut self, f: impl Fn(&T) -> bool + 'a) -> Self {
+        self.accepts = Box::new(f);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`DropTarget`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`DropTarget`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Returns the payload being dragged, if the [`DropTarget`] accepts it.
+    fn accepted(&self) -> Option<&T> {
+        self.dragging
+            .as_ref()
+            .filter(|payload| (self.accepts)(payload))
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for DropTarget<'a, T, Message, Renderer>
+where
+    T: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.pad(self.padding);
+
+        let mut content = self.content.layout(renderer, &limits);
+        content.move_to(Point::new(
+            self.padding.left.into(),
+            self.padding.top.into(),
+        ));
+
+        let size = limits.resolve(content.size()).pad(self.padding);
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.on_event(
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. }) = event
+        {
+            if layout.bounds().contains(cursor_position) {
+                if let Some(payload) = self.accepted().cloned() {
+                    shell.publish((self.on_drop)(payload));
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.content.mouse_interaction(
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let drop_style = if self.accepted().is_some() {
+            if bounds.contains(cursor_position) {
+                self.style_sheet.hovered()
+            } else {
+                self.style_sheet.accepting()
+            }
+        } else {
+            self.style_sheet.active()
+        };
+
+        if drop_style.background.is_some() || drop_style.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: drop_style.border_radius,
+                    border_width: drop_style.border_width,
+                    border_color: drop_style.border_color,
+                },
+                drop_style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        self.content.draw(
+            renderer,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.padding.hash(state);
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+}
+
+impl<'a, T, Message, Renderer> From<DropTarget<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        drop_target: DropTarget<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(drop_target)
+    }
+}
@@ -0,0 +1,350 @@
+//! Receive files dropped from outside the application on some content.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::window;
+use crate::{
+    Background, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
+    Point, Rectangle, Shell, Widget,
+};
+
+use std::hash::Hash;
+use std::path::PathBuf;
+
+pub use iced_style::drop_target::{Style, StyleSheet};
+
+/// A wrapper that receives the files dragged and dropped over its bounds.
+///
+/// Files hovered and dropped on a window are reported to every widget, along
+/// with the position of the cursor. A [`FileDropZone`] only reacts to the
+/// files that are over its own bounds, which lets a window have multiple
+/// upload areas.
+///
+/// While files are dragged over the window, the [`FileDropZone`] uses the
+/// [`StyleSheet::accepting`] style, and the [`StyleSheet::hovered`] style once
+/// they are over it.
+///
+/// Some platforms do not report the cursor while files are dragged over a
+/// window, so its position may be stale or unknown when the files are dropped.
+/// A [`FileDropZone`] can use [`catch_all`](Self::catch_all) to also receive
+/// the files dropped while the position of the cursor is unknown.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{file_drop_zone, Text};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type FileDropZone<'a, Message> =
+/// #     iced_native::widget::FileDropZone<'a, Message, Null>;
+/// #
+/// use std::path::PathBuf;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     AvatarDropped(PathBuf),
+/// }
+///
+/// let mut state = file_drop_zone::State::new();
+/// let avatar = FileDropZone::new(&mut state, Text::new("Drop an avatar"))
+///     .on_drop(Message::AvatarDropped)
+///     .padding(20);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct FileDropZone<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    on_hover: Option<Box<dyn Fn(PathBuf) -> Message + 'a>>,
+    on_drop: Option<Box<dyn Fn(PathBuf) -> Message + 'a>>,
+    on_leave: Option<Message>,
+    padding: Padding,
+    catch_all: bool,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> FileDropZone<'a, Message, Renderer> {
+    /// Creates a new [`FileDropZone`] with the given [`State`] and content.
+    pub fn new(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        FileDropZone {
+            state,
+            content: content.into(),
+            on_hover: None,
+            on_drop: None,
+            on_leave: None,
+            padding: Padding::ZERO,
+            catch_all: false,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the function that will be called for every file that is dragged
+    /// over the [`FileDropZone`].
+    pub fn on_hover(mut self, f: impl Fn(PathBuf) -> Message + 'a) -> Self {
+        self.on_hover = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the function that will be called for every file that is dropped
+    /// on the [`FileDropZone`].
+    pub fn on_drop(mut self, f: impl Fn(PathBuf) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when the hovered files leave
+    /// the [`FileDropZone`] without being dropped on it.
+    pub fn on_leave(mut self, message: Message) -> Self {
+        self.on_leave = Some(message);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`FileDropZone`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets whether the [`FileDropZone`] receives the files dropped while the
+    /// position of the cursor is unknown, as if they were dropped over it.
+    pub fn catch_all(mut self, catch_all: bool) -> Self {
+        self.catch_all = catch_all;
+        self
+    }
+
+    /// Sets the style of the [`FileDropZone`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for FileDropZone<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.pad(self.padding);
+
+        let mut content = self.content.layout(renderer, &limits);
+        content.move_to(Point::new(
+            self.padding.left.into(),
+            self.padding.top.into(),
+        ));
+
+        let size = limits.resolve(content.size()).pad(self.padding);
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.on_event(
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        // TODO: Type-safe cursor availability
+        let is_cursor_unknown =
+            cursor_position.x < 0.0 || cursor_position.y < 0.0;
+
+        let is_mouse_over = layout.bounds().contains(cursor_position)
+            || (self.catch_all && is_cursor_unknown);
+
+        match event {
+            Event::Window(window::Event::FileHovered(path)) => {
+                self.state.files.push(path.clone());
+
+                if is_mouse_over {
+                    self.state.is_hovered = true;
+
+                    if let Some(on_hover) = &self.on_hover {
+                        shell.publish(on_hover(path));
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if !self.state.files.is_empty()
+                    && is_mouse_over != self.state.is_hovered =>
+            {
+                self.state.is_hovered = is_mouse_over;
+
+                if is_mouse_over {
+                    if let Some(on_hover) = &self.on_hover {
+                        for path in &self.state.files {
+                            shell.publish(on_hover(path.clone()));
+                        }
+                    }
+                } else if let Some(on_leave) = self.on_leave.clone() {
+                    shell.publish(on_leave);
+                }
+            }
+            Event::Window(window::Event::FileDropped(path)) => {
+                self.state.files.retain(|file| file != &path);
+
+                if self.state.files.is_empty() {
+                    self.state.is_hovered = false;
+                }
+
+                if is_mouse_over {
+                    if let Some(on_drop) = &self.on_drop {
+                        shell.publish(on_drop(path));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Window(window::Event::FilesHoveredLeft) => {
+                self.state.files.clear();
+
+                if self.state.is_hovered {
+                    self.state.is_hovered = false;
+
+                    if let Some(on_leave) = self.on_leave.clone() {
+                        shell.publish(on_leave);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.content.mouse_interaction(
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let drop_style = if self.state.is_hovered {
+            self.style_sheet.hovered()
+        } else if !self.state.files.is_empty() {
+            self.style_sheet.accepting()
+        } else {
+            self.style_sheet.active()
+        };
+
+        if drop_style.background.is_some() || drop_style.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: drop_style.border_radius,
+                    border_width: drop_style.border_width,
+                    border_color: drop_style.border_color,
+                },
+                drop_style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        self.content.draw(
+            renderer,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.padding.hash(state);
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+}
+
+/// The local state of a [`FileDropZone`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    files: Vec<PathBuf>,
+    is_hovered: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether files are currently dragged over the [`FileDropZone`].
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+
+    /// Returns the files currently dragged over the window.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+}
+
+impl<'a, Message, Renderer> From<FileDropZone<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        file_drop_zone: FileDropZone<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(file_drop_zone)
+    }
+}
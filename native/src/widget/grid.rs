@@ -0,0 +1,385 @@
+//! Distribute content in a grid of columns and rows.
+use crate::event::{self, Event};
+use crate::layout::{self, grid::Placement};
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::{
+    Alignment, Clipboard, Element, Hasher, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A container that distributes its contents in a grid of columns and rows.
+///
+/// The columns and rows of a [`Grid`] are defined by their [`Length`]:
+///
+/// - [`Length::Units`] produces a track of a fixed size.
+/// - [`Length::Shrink`] produces a track that fits its largest [`Cell`].
+/// - [`Length::Fill`] and [`Length::FillPortion`] produce tracks that share
+///   the remaining space.
+///
+/// Rows are added as needed, shrinking to fit their cells unless specified
+/// with [`rows`](Self::rows).
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{grid, Text};
+/// # use iced_native::{renderer::Null, Length};
+/// #
+/// # type Grid<'a, Message> = iced_native::widget::Grid<'a, Message, Null>;
+/// #
+/// let form: Grid<()> =
+///     Grid::with_columns(vec![Length::Shrink, Length::Fill])
+///         .spacing(10)
+///         .push(Text::new("Name"))
+///         .push(Text::new("Ferris"))
+///         .push(Text::new("Language"))
+///         .push(Text::new("Rust"))
+///         .push_cell(grid::Cell::new(Text::new("Submit")).column_span(2));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer> {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    column_spacing: u16,
+    row_spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    min_width: u32,
+    min_height: u32,
+    aspect_ratio: Option<f32>,
+    cells: Vec<(Placement, Element<'a, Message, Renderer>)>,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`] with the given amount of columns, sharing
+    /// the available width equally.
+    pub fn new(columns: usize) -> Self {
+        Self::with_columns(vec![Length::Fill; columns.max(1)])
+    }
+
+    /// Creates an empty [`Grid`] with the given column tracks.
+    pub fn with_columns(columns: Vec<Length>) -> Self {
+        Grid {
+            columns,
+            rows: Vec::new(),
+            column_spacing: 0,
+            row_spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            min_width: 0,
+            min_height: 0,
+            aspect_ratio: None,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Sets the row tracks of the [`Grid`].
+    pub fn rows(mut self, rows: Vec<Length>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets both the horizontal and vertical spacing _between_ cells.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the horizontal spacing _between_ columns.
+    pub fn column_spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn row_spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Grid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Grid`].
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Grid`].
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the minimum width of the [`Grid`].
+    pub fn min_width(mut self, min_width: u32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the minimum height of the [`Grid`].
+    pub fn min_height(mut self, min_height: u32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
+    /// Sets the aspect ratio of the [`Grid`], as its width divided by its
+    /// height.
+    ///
+    /// The [`Grid`] keeps this ratio whenever its constraints allow it.
+    pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Grid`], in the next free cell.
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.cells.push((Placement::default(), child.into()));
+        self
+    }
+
+    /// Adds a [`Cell`] to the [`Grid`].
+    pub fn push_cell(mut self, cell: Cell<'a, Message, Renderer>) -> Self {
+        self.cells.push((cell.placement, cell.content));
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .min_width(self.min_width)
+            .min_height(self.min_height)
+            .width(self.width)
+            .height(self.height);
+
+        let limits = match self.aspect_ratio {
+            Some(ratio) => limits.aspect_ratio(ratio),
+            None => limits,
+        };
+
+        layout::grid::resolve(
+            renderer,
+            &limits,
+            self.padding,
+            Size::new(
+                f32::from(self.column_spacing),
+                f32::from(self.row_spacing),
+            ),
+            &self.columns,
+            &self.rows,
+            &self.cells,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.cells
+            .iter_mut()
+            .zip(layout.children())
+            .map(|((_, child), layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.cells
+            .iter()
+            .zip(layout.children())
+            .map(|((_, child), layout)| {
+                child.widget.mouse_interaction(
+                    layout,
+                    cursor_position,
+                    viewport,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        for ((_, child), layout) in self.cells.iter().zip(layout.children()) {
+            child.draw(renderer, style, layout, cursor_position, viewport);
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.min_width.hash(state);
+        self.min_height.hash(state);
+        self.aspect_ratio.map(f32::to_bits).hash(state);
+        self.columns.hash(state);
+        self.rows.hash(state);
+        self.column_spacing.hash(state);
+        self.row_spacing.hash(state);
+        self.padding.hash(state);
+
+        for (placement, child) in &self.cells {
+            placement.hash(state);
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.cells
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|((_, child), layout)| child.widget.overlay(layout))
+            .next()
+    }
+}
+
+/// The content of a [`Grid`] with an explicit placement, span or alignment.
+#[allow(missing_debug_implementations)]
+pub struct Cell<'a, Message, Renderer> {
+    placement: Placement,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Cell<'a, Message, Renderer> {
+    /// Creates a new [`Cell`] with the given content.
+    ///
+    /// By default, it is placed in the next free cell of the [`Grid`].
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Cell {
+            placement: Placement::default(),
+            content: content.into(),
+        }
+    }
+
+    /// Sets the column of the [`Cell`].
+    pub fn column(mut self, column: usize) -> Self {
+        self.placement.column = Some(column);
+        self
+    }
+
+    /// Sets the row of the [`Cell`].
+    pub fn row(mut self, row: usize) -> Self {
+        self.placement.row = Some(row);
+        self
+    }
+
+    /// Sets the amount of columns spanned by the [`Cell`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn column_span(mut self, span: usize) -> Self {
+        self.placement.column_span = span.max(1);
+        self
+    }
+
+    /// Sets the amount of rows spanned by the [`Cell`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn row_span(mut self, span: usize) -> Self {
+        self.placement.row_span = span.max(1);
+        self
+    }
+
+    /// Sets the horizontal alignment of the content of the [`Cell`].
+    pub fn align_x(mut self, alignment: Alignment) -> Self {
+        self.placement.align_x = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the content of the [`Cell`].
+    pub fn align_y(mut self, alignment: Alignment) -> Self {
+        self.placement.align_y = alignment;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        grid: Grid<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(grid)
+    }
+}
@@ -1,16 +1,23 @@
 //! Display images in your user interface.
+pub mod animated;
 pub mod viewer;
+pub use animated::AnimatedImage;
 pub use viewer::Viewer;
 
 use crate::image;
 use crate::layout;
 use crate::renderer;
-use crate::{Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::{
+    ContentFit, Element, Hasher, Layout, Length, Point, Rectangle, Size,
+    Vector, Widget,
+};
 
 use std::hash::Hash;
 
 /// A frame that displays an image while keeping aspect ratio.
 ///
+/// How the image fits in its frame can be changed with a [`ContentFit`].
+///
 /// # Example
 ///
 /// ```
@@ -26,6 +33,8 @@ pub struct Image<Handle> {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
+    filter_method: image::FilterMethod,
 }
 
 impl<Handle> Image<Handle> {
@@ -35,6 +44,8 @@ impl<Handle> Image<Handle> {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            filter_method: image::FilterMethod::Linear,
         }
     }
 
@@ -49,6 +60,75 @@ impl<Handle> Image<Handle> {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Image`].
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    ///
+    /// [`FilterMethod`]: image::FilterMethod
+    /// [`FilterMethod::Linear`]: image::FilterMethod::Linear
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+}
+
+/// Computes the layout of some content of the given size fitted with a
+/// [`ContentFit`].
+pub(crate) fn layout(
+    limits: &layout::Limits,
+    content_size: Size,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+) -> layout::Node {
+    let raw_size = limits.width(width).height(height).resolve(content_size);
+    let full_size = content_fit.fit(content_size, raw_size);
+
+    // A shrinking side takes the size of the fitted content, while any other
+    // side keeps the size it was given
+    let final_size = Size {
+        width: match width {
+            Length::Shrink => f32::min(raw_size.width, full_size.width),
+            _ => raw_size.width,
+        },
+        height: match height {
+            Length::Shrink => f32::min(raw_size.height, full_size.height),
+            _ => raw_size.height,
+        },
+    };
+
+    layout::Node::new(final_size)
+}
+
+/// Returns the bounds of some content of the given size fitted with a
+/// [`ContentFit`] and centered inside the given bounds.
+///
+/// The returned bounds can be bigger than the given ones; the content should
+/// be clipped in that case.
+pub(crate) fn fitted_bounds(
+    bounds: Rectangle,
+    content_size: Size,
+    content_fit: ContentFit,
+) -> Rectangle {
+    let size = content_fit.fit(content_size, bounds.size());
+
+    Rectangle {
+        width: size.width,
+        height: size.height,
+        ..bounds
+    } + Vector::new(
+        (bounds.width - size.width) / 2.0,
+        (bounds.height - size.height) / 2.0,
+    )
 }
 
 impl<Message, Renderer, Handle> Widget<Message, Renderer> for Image<Handle>
@@ -71,22 +151,13 @@ where
     ) -> layout::Node {
         let (width, height) = renderer.dimensions(&self.handle);
 
-        let aspect_ratio = width as f32 / height as f32;
-
-        let mut size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
-
-        let viewport_aspect_ratio = size.width / size.height;
-
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
-        }
-
-        layout::Node::new(size)
+        layout(
+            limits,
+            Size::new(width as f32, height as f32),
+            self.width,
+            self.height,
+            self.content_fit,
+        )
     }
 
     fn draw(
@@ -97,7 +168,30 @@ where
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        renderer.draw(self.handle.clone(), layout.bounds());
+        let (width, height) = renderer.dimensions(&self.handle);
+        let bounds = layout.bounds();
+
+        let drawing_bounds = fitted_bounds(
+            bounds,
+            Size::new(width as f32, height as f32),
+            self.content_fit,
+        );
+
+        let render = |renderer: &mut Renderer| {
+            renderer.draw(
+                self.handle.clone(),
+                self.filter_method,
+                drawing_bounds,
+            )
+        };
+
+        if drawing_bounds.width > bounds.width
+            || drawing_bounds.height > bounds.height
+        {
+            renderer.with_layer(bounds, render);
+        } else {
+            render(renderer)
+        }
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -107,6 +201,7 @@ where
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
     }
 }
 
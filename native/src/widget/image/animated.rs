@@ -0,0 +1,385 @@
+//! Play animated images, like GIFs.
+use crate::event::{self, Event};
+use crate::image;
+use crate::layout;
+use crate::renderer;
+use crate::window;
+use crate::{
+    Clipboard, ContentFit, Element, Hasher, Layout, Length, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+use std::hash::{Hash, Hasher as _};
+use std::time::{Duration, Instant};
+
+/// A frame that plays an animated image while keeping aspect ratio.
+///
+/// The frames of the image are advanced on schedule while the [`State`] of
+/// the [`AnimatedImage`] is playing. An image that is not animated is simply
+/// displayed, like an [`Image`] would.
+///
+/// Animated GIF, PNG and WebP images are supported.
+///
+/// [`Image`]: crate::widget::Image
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::widget::image::{animated, AnimatedImage};
+/// # use iced_native::image;
+/// #
+/// let mut state = animated::State::new();
+///
+/// let animation =
+///     AnimatedImage::<image::Handle>::new(&mut state, "resources/loading.gif")
+///         .looping(false);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct AnimatedImage<'a, Handle> {
+    state: &'a mut State,
+    handle: Handle,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+    is_looping: bool,
+}
+
+impl<'a, Handle> AnimatedImage<'a, Handle> {
+    /// Creates a new [`AnimatedImage`] with the given [`State`] and image.
+    pub fn new<T: Into<Handle>>(state: &'a mut State, handle: T) -> Self {
+        AnimatedImage {
+            state,
+            handle: handle.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            filter_method: image::FilterMethod::Linear,
+            is_looping: true,
+        }
+    }
+
+    /// Sets the width of the [`AnimatedImage`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`AnimatedImage`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`AnimatedImage`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`AnimatedImage`].
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    ///
+    /// [`FilterMethod`]: image::FilterMethod
+    /// [`FilterMethod::Linear`]: image::FilterMethod::Linear
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets whether the [`AnimatedImage`] starts over once its last frame has
+    /// been shown.
+    ///
+    /// Defaults to `true`. When it does not loop, the [`AnimatedImage`] stops
+    /// at its last frame.
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
+}
+
+impl<'a, Message, Renderer, Handle> Widget<Message, Renderer>
+    for AnimatedImage<'a, Handle>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + Hash,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (width, height) = renderer.dimensions(&self.handle);
+
+        super::layout(
+            limits,
+            Size::new(width as f32, height as f32),
+            self.width,
+            self.height,
+            self.content_fit,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if self.state.is_playing {
+                let handle = {
+                    let mut hasher = Hasher::default();
+                    self.handle.hash(&mut hasher);
+
+                    hasher.finish()
+                };
+
+                // The delays are only fetched again when the image changes
+                if self.state.delays_of != Some(handle) {
+                    self.state.delays = renderer.frame_delays(&self.handle);
+                    self.state.delays_of = Some(handle);
+                }
+
+                if let Some(next_frame_at) =
+                    self.state.advance(now, self.is_looping)
+                {
+                    shell.request_redraw(next_frame_at);
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let (width, height) = renderer.dimensions(&self.handle);
+        let bounds = layout.bounds();
+
+        let drawing_bounds = super::fitted_bounds(
+            bounds,
+            Size::new(width as f32, height as f32),
+            self.content_fit,
+        );
+
+        let render = |renderer: &mut Renderer| {
+            renderer.draw_frame(
+                self.handle.clone(),
+                self.state.frame,
+                self.filter_method,
+                image::Rotation::None,
+                drawing_bounds,
+            )
+        };
+
+        if drawing_bounds.width > bounds.width
+            || drawing_bounds.height > bounds.height
+        {
+            renderer.with_layer(bounds, render);
+        } else {
+            render(renderer)
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.handle.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.content_fit.hash(state);
+    }
+}
+
+/// The local state of an [`AnimatedImage`].
+#[derive(Debug, Clone)]
+pub struct State {
+    frame: usize,
+    next_frame_at: Option<Instant>,
+    is_playing: bool,
+    delays: Vec<Duration>,
+    delays_of: Option<u64>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            frame: 0,
+            next_frame_at: None,
+            is_playing: true,
+            delays: Vec::new(),
+            delays_of: None,
+        }
+    }
+}
+
+impl State {
+    /// Creates a new [`State`] that is playing from the first frame.
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Creates a new [`State`] that is paused on the first frame.
+    pub fn paused() -> Self {
+        State {
+            is_playing: false,
+            ..State::default()
+        }
+    }
+
+    /// Resumes the animation from its current frame.
+    ///
+    /// An animation that is not looping stays on its last frame once it has
+    /// finished; [`restart`](Self::restart) it to play it again.
+    pub fn play(&mut self) {
+        self.is_playing = true;
+        self.next_frame_at = None;
+    }
+
+    /// Pauses the animation on its current frame.
+    pub fn pause(&mut self) {
+        self.is_playing = false;
+        self.next_frame_at = None;
+    }
+
+    /// Goes back to the first frame of the animation, without changing
+    /// whether it is playing or not.
+    pub fn restart(&mut self) {
+        self.frame = 0;
+        self.next_frame_at = None;
+    }
+
+    /// Returns whether the animation is playing.
+    pub fn is_playing(&self) -> bool {
+        self.is_playing
+    }
+
+    /// Returns the index of the frame that is currently shown.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Advances the animation to the frame that should be shown at the given
+    /// time.
+    ///
+    /// Returns the time when the next frame should be shown, if any.
+    fn advance(&mut self, now: Instant, is_looping: bool) -> Option<Instant> {
+        if self.delays.len() < 2 {
+            return None;
+        }
+
+        if self.frame >= self.delays.len() {
+            self.frame = 0;
+        }
+
+        let mut next_frame_at =
+            self.next_frame_at.unwrap_or(now + self.delays[self.frame]);
+
+        while next_frame_at <= now {
+            if self.frame + 1 < self.delays.len() {
+                self.frame += 1;
+            } else if is_looping {
+                self.frame = 0;
+            } else {
+                self.pause();
+
+                return None;
+            }
+
+            next_frame_at += self.delays[self.frame];
+        }
+
+        self.next_frame_at = Some(next_frame_at);
+
+        Some(next_frame_at)
+    }
+}
+
+impl<'a, Message, Renderer, Handle> From<AnimatedImage<'a, Handle>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + image::Renderer<Handle = Handle>,
+    Message: 'a,
+    Handle: Clone + Hash + 'a,
+{
+    fn from(
+        animated_image: AnimatedImage<'a, Handle>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(animated_image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELAYS: [Duration; 3] = [
+        Duration::from_millis(100),
+        Duration::from_millis(200),
+        Duration::from_millis(100),
+    ];
+
+    #[test]
+    fn advances_frames_on_schedule() {
+        let start = Instant::now();
+        let mut state = State {
+            delays: DELAYS.to_vec(),
+            ..State::new()
+        };
+
+        assert_eq!(
+            state.advance(start, true),
+            Some(start + Duration::from_millis(100))
+        );
+        assert_eq!(state.frame(), 0);
+
+        assert_eq!(
+            state.advance(start + Duration::from_millis(150), true),
+            Some(start + Duration::from_millis(300))
+        );
+        assert_eq!(state.frame(), 1);
+
+        let _ = state.advance(start + Duration::from_millis(450), true);
+        assert_eq!(state.frame(), 0);
+    }
+
+    #[test]
+    fn stops_at_last_frame_when_not_looping() {
+        let start = Instant::now();
+        let mut state = State {
+            delays: DELAYS.to_vec(),
+            ..State::new()
+        };
+
+        let _ = state.advance(start, false);
+
+        assert_eq!(
+            state.advance(start + Duration::from_millis(400), false),
+            None
+        );
+        assert_eq!(state.frame(), 2);
+        assert!(!state.is_playing());
+    }
+}
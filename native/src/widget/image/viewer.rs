@@ -4,14 +4,24 @@ use crate::image;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
+use crate::touch;
 use crate::{
-    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Size,
-    Vector, Widget,
+    Clipboard, ContentFit, Element, Hasher, Layout, Length, Point, Rectangle,
+    Shell, Size, Vector, Widget,
 };
 
 use std::hash::Hash;
 
+/// The scale a double click zooms to when the image of a [`Viewer`] already
+/// fits it at its actual size.
+const DOUBLE_CLICK_SCALE: f32 = 2.0;
+
 /// A frame that displays an image with the ability to zoom in/out and pan.
+///
+/// The image can be zoomed with the mouse wheel, by double clicking it, or by
+/// pinching it on a touch screen. The [`State`] of a [`Viewer`] can also be
+/// changed directly; for instance, to rotate the image or to keep two
+/// [`Viewer`]s in sync.
 #[allow(missing_debug_implementations)]
 pub struct Viewer<'a, Handle> {
     state: &'a mut State,
@@ -21,6 +31,8 @@ pub struct Viewer<'a, Handle> {
     min_scale: f32,
     max_scale: f32,
     scale_step: f32,
+    content_fit: ContentFit,
+    filter_method: image::FilterMethod,
     handle: Handle,
 }
 
@@ -35,6 +47,8 @@ impl<'a, Handle> Viewer<'a, Handle> {
             min_scale: 0.25,
             max_scale: 10.0,
             scale_step: 0.10,
+            content_fit: ContentFit::ScaleDown,
+            filter_method: image::FilterMethod::Linear,
             handle,
         }
     }
@@ -82,33 +96,177 @@ impl<'a, Handle> Viewer<'a, Handle> {
         self
     }
 
+    /// Sets the [`ContentFit`] of the image of the [`Viewer`] when it is not
+    /// zoomed.
+    ///
+    /// Default is [`ContentFit::ScaleDown`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the image of the [`Viewer`].
+    ///
+    /// Default is [`FilterMethod::Linear`]
+    ///
+    /// [`FilterMethod`]: image::FilterMethod
+    /// [`FilterMethod::Linear`]: image::FilterMethod::Linear
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Returns the dimensions of the underlying image, once rotated.
+    fn dimensions<Renderer>(&self, renderer: &Renderer) -> Size
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let (width, height) = renderer.dimensions(&self.handle);
+
+        if self.state.rotation.is_sideways() {
+            Size::new(height as f32, width as f32)
+        } else {
+            Size::new(width as f32, height as f32)
+        }
+    }
+
     /// Returns the bounds of the underlying image, given the bounds of
-    /// the [`Viewer`]. Scaling will be applied and original aspect ratio
-    /// will be respected.
+    /// the [`Viewer`]. The [`ContentFit`] and scaling will be applied.
     fn image_size<Renderer>(&self, renderer: &Renderer, bounds: Size) -> Size
     where
         Renderer: image::Renderer<Handle = Handle>,
     {
-        let (width, height) = renderer.dimensions(&self.handle);
+        let size = self.content_fit.fit(self.dimensions(renderer), bounds);
+
+        Size::new(
+            size.width * self.state.scale,
+            size.height * self.state.scale,
+        )
+    }
+
+    /// Returns the scale that shows the underlying image at its actual size,
+    /// given the bounds of the [`Viewer`].
+    fn actual_scale<Renderer>(&self, renderer: &Renderer, bounds: Size) -> f32
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let dimensions = self.dimensions(renderer);
+        let size = self.content_fit.fit(dimensions, bounds);
+
+        if size.width > 0.0 {
+            dimensions.width / size.width
+        } else {
+            1.0
+        }
+    }
+
+    /// Zooms the underlying image to the given scale, keeping the given point
+    /// of the [`Viewer`] in place.
+    fn zoom<Renderer>(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        origin: Point,
+        scale: f32,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let previous_scale = self.state.scale;
 
-        let (width, height) = {
-            let dimensions = (width as f32, height as f32);
+        self.state.scale = scale.max(self.min_scale).min(self.max_scale);
 
-            let width_ratio = bounds.width / dimensions.0;
-            let height_ratio = bounds.height / dimensions.1;
+        let image_size = self.image_size(renderer, bounds.size());
+
+        let factor = self.state.scale / previous_scale - 1.0;
 
-            let ratio = width_ratio.min(height_ratio);
+        let origin_to_center = origin - bounds.center();
 
-            let scale = self.state.scale;
+        let adjustment =
+            origin_to_center * factor + self.state.current_offset * factor;
 
-            if ratio < 1.0 {
-                (dimensions.0 * ratio * scale, dimensions.1 * ratio * scale)
+        self.state.current_offset = Vector::new(
+            if image_size.width > bounds.width {
+                self.state.current_offset.x + adjustment.x
             } else {
-                (dimensions.0 * scale, dimensions.1 * scale)
-            }
+                0.0
+            },
+            if image_size.height > bounds.height {
+                self.state.current_offset.y + adjustment.y
+            } else {
+                0.0
+            },
+        );
+    }
+
+    /// Toggles the underlying image between fitting the [`Viewer`] and its
+    /// actual size, zooming into the given point.
+    fn toggle_zoom<Renderer>(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        origin: Point,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        if (self.state.scale - 1.0).abs() > f32::EPSILON {
+            self.state.fit();
+            return;
+        }
+
+        let actual_scale = self.actual_scale(renderer, bounds.size());
+
+        let scale = if (actual_scale - 1.0).abs() > f32::EPSILON {
+            actual_scale
+        } else {
+            DOUBLE_CLICK_SCALE
         };
 
-        Size::new(width, height)
+        self.zoom(renderer, bounds, origin, scale);
+    }
+
+    /// Pans the underlying image, if grabbed, to follow the given position.
+    fn pan<Renderer>(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        position: Point,
+    ) -> event::Status
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        if let Some(origin) = self.state.cursor_grabbed_at {
+            let image_size = self.image_size(renderer, bounds.size());
+
+            let hidden_width =
+                (image_size.width - bounds.width / 2.0).max(0.0).round();
+
+            let hidden_height =
+                (image_size.height - bounds.height / 2.0).max(0.0).round();
+
+            let delta = position - origin;
+
+            let x = if bounds.width < image_size.width {
+                (self.state.starting_offset.x - delta.x)
+                    .min(hidden_width)
+                    .max(-hidden_width)
+            } else {
+                0.0
+            };
+
+            let y = if bounds.height < image_size.height {
+                (self.state.starting_offset.y - delta.y)
+                    .min(hidden_height)
+                    .max(-hidden_height)
+            } else {
+                0.0
+            };
+
+            self.state.current_offset = Vector::new(x, y);
+
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
     }
 }
 
@@ -131,12 +289,12 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let (width, height) = renderer.dimensions(&self.handle);
+        let Size { width, height } = self.dimensions(renderer);
 
         let mut size = limits
             .width(self.width)
             .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
+            .resolve(Size::new(width, height));
 
         let expansion_size = if height > width {
             self.width
@@ -148,12 +306,12 @@ where
         // If they are Fill|Portion let them expand within their alotted space.
         match expansion_size {
             Length::Shrink | Length::Units(_) => {
-                let aspect_ratio = width as f32 / height as f32;
+                let aspect_ratio = width / height;
                 let viewport_aspect_ratio = size.width / size.height;
                 if viewport_aspect_ratio > aspect_ratio {
-                    size.width = width as f32 * size.height / height as f32;
+                    size.width = width * size.height / height;
                 } else {
-                    size.height = height as f32 * size.width / width as f32;
+                    size.height = height * size.width / width;
                 }
             }
             Length::Fill | Length::FillPortion(_) => {}
@@ -174,6 +332,8 @@ where
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
 
+        self.state.actual_scale = self.actual_scale(renderer, bounds.size());
+
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta })
                 if is_mouse_over =>
@@ -186,38 +346,13 @@ where
                         if y < 0.0 && previous_scale > self.min_scale
                             || y > 0.0 && previous_scale < self.max_scale
                         {
-                            self.state.scale = (if y > 0.0 {
-                                self.state.scale * (1.0 + self.scale_step)
+                            let scale = if y > 0.0 {
+                                previous_scale * (1.0 + self.scale_step)
                             } else {
-                                self.state.scale / (1.0 + self.scale_step)
-                            })
-                            .max(self.min_scale)
-                            .min(self.max_scale);
-
-                            let image_size =
-                                self.image_size(renderer, bounds.size());
-
-                            let factor =
-                                self.state.scale / previous_scale - 1.0;
-
-                            let cursor_to_center =
-                                cursor_position - bounds.center();
-
-                            let adjustment = cursor_to_center * factor
-                                + self.state.current_offset * factor;
-
-                            self.state.current_offset = Vector::new(
-                                if image_size.width > bounds.width {
-                                    self.state.current_offset.x + adjustment.x
-                                } else {
-                                    0.0
-                                },
-                                if image_size.height > bounds.height {
-                                    self.state.current_offset.y + adjustment.y
-                                } else {
-                                    0.0
-                                },
-                            );
+                                previous_scale / (1.0 + self.scale_step)
+                            };
+
+                            self.zoom(renderer, bounds, cursor_position, scale);
                         }
                     }
                 }
@@ -227,6 +362,14 @@ where
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
                 if is_mouse_over =>
             {
+                let click =
+                    mouse::Click::new(cursor_position, self.state.last_click);
+
+                if let mouse::click::Kind::Double = click.kind() {
+                    self.toggle_zoom(renderer, bounds, cursor_position);
+                }
+
+                self.state.last_click = Some(click);
                 self.state.cursor_grabbed_at = Some(cursor_position);
                 self.state.starting_offset = self.state.current_offset;
 
@@ -240,42 +383,83 @@ where
                 event::Status::Captured
             }
             Event::Mouse(mouse::Event::CursorMoved { position }) => {
-                if let Some(origin) = self.state.cursor_grabbed_at {
-                    let image_size = self.image_size(renderer, bounds.size());
-
-                    let hidden_width = (image_size.width - bounds.width / 2.0)
-                        .max(0.0)
-                        .round();
-
-                    let hidden_height = (image_size.height
-                        - bounds.height / 2.0)
-                        .max(0.0)
-                        .round();
-
-                    let delta = position - origin;
-
-                    let x = if bounds.width < image_size.width {
-                        (self.state.starting_offset.x - delta.x)
-                            .min(hidden_width)
-                            .max(-hidden_width)
-                    } else {
-                        0.0
-                    };
-
-                    let y = if bounds.height < image_size.height {
-                        (self.state.starting_offset.y - delta.y)
-                            .min(hidden_height)
-                            .max(-hidden_height)
-                    } else {
-                        0.0
-                    };
-
-                    self.state.current_offset = Vector::new(x, y);
-
-                    event::Status::Captured
-                } else {
-                    event::Status::Ignored
+                self.pan(renderer, bounds, position)
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position })
+                if bounds.contains(position) =>
+            {
+                match self.state.fingers {
+                    [None, None] => {
+                        self.state.fingers[0] = Some((id, position));
+                        self.state.cursor_grabbed_at = Some(position);
+                        self.state.starting_offset = self.state.current_offset;
+                    }
+                    [Some(_), None] => {
+                        self.state.fingers[1] = Some((id, position));
+                        self.state.cursor_grabbed_at = None;
+                    }
+                    _ => {}
                 }
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                match self.state.fingers {
+                    [Some((first, a)), Some((second, b))]
+                        if first == id || second == id =>
+                    {
+                        let previous_distance = a.distance(b);
+
+                        let (a, b) = if first == id {
+                            (position, b)
+                        } else {
+                            (a, position)
+                        };
+
+                        self.state.fingers =
+                            [Some((first, a)), Some((second, b))];
+
+                        if previous_distance > 0.0 {
+                            let center = Point::new(
+                                (a.x + b.x) / 2.0,
+                                (a.y + b.y) / 2.0,
+                            );
+
+                            let scale = self.state.scale * a.distance(b)
+                                / previous_distance;
+
+                            self.zoom(renderer, bounds, center, scale);
+                        }
+
+                        event::Status::Captured
+                    }
+                    [Some((finger, _)), None] if finger == id => {
+                        self.state.fingers[0] = Some((finger, position));
+
+                        self.pan(renderer, bounds, position)
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            Event::Touch(touch::Event::FingerLifted { id, .. })
+            | Event::Touch(touch::Event::FingerLost { id, .. }) => {
+                let remaining = match self.state.fingers {
+                    [Some((finger, _)), remaining]
+                    | [remaining, Some((finger, _))]
+                        if finger == id =>
+                    {
+                        remaining
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                // The remaining finger, if any, keeps panning the image
+                self.state.fingers = [remaining, None];
+                self.state.cursor_grabbed_at =
+                    remaining.map(|(_, position)| position);
+                self.state.starting_offset = self.state.current_offset;
+
+                event::Status::Captured
             }
             _ => event::Status::Ignored,
         }
@@ -317,18 +501,30 @@ where
                 bounds.height / 2.0 - image_size.height / 2.0,
             );
 
-            image_top_left - self.state.offset(bounds, image_size)
+            image_top_left - self.state.clamped_offset(bounds, image_size)
+        };
+
+        // The image is rotated around the center of the bounds it is drawn in
+        let unrotated_size = if self.state.rotation.is_sideways() {
+            Size::new(image_size.height, image_size.width)
+        } else {
+            image_size
         };
 
         renderer.with_layer(bounds, |renderer| {
             renderer.with_translation(translation, |renderer| {
-                image::Renderer::draw(
+                image::Renderer::draw_frame(
                     renderer,
                     self.handle.clone(),
+                    0,
+                    self.filter_method,
+                    self.state.rotation,
                     Rectangle {
-                        x: bounds.x,
-                        y: bounds.y,
-                        ..Rectangle::with_size(image_size)
+                        x: bounds.x
+                            + (image_size.width - unrotated_size.width) / 2.0,
+                        y: bounds.y
+                            + (image_size.height - unrotated_size.height) / 2.0,
+                        ..Rectangle::with_size(unrotated_size)
                     },
                 )
             });
@@ -342,6 +538,7 @@ where
         self.width.hash(state);
         self.height.hash(state);
         self.padding.hash(state);
+        self.state.rotation.hash(state);
 
         self.handle.hash(state);
     }
@@ -351,18 +548,26 @@ where
 #[derive(Debug, Clone, Copy)]
 pub struct State {
     scale: f32,
+    actual_scale: f32,
+    rotation: image::Rotation,
     starting_offset: Vector,
     current_offset: Vector,
     cursor_grabbed_at: Option<Point>,
+    last_click: Option<mouse::Click>,
+    fingers: [Option<(touch::Finger, Point)>; 2],
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
             scale: 1.0,
+            actual_scale: 1.0,
+            rotation: image::Rotation::None,
             starting_offset: Vector::default(),
             current_offset: Vector::default(),
             cursor_grabbed_at: None,
+            last_click: None,
+            fingers: [None, None],
         }
     }
 }
@@ -373,9 +578,87 @@ impl State {
         State::default()
     }
 
+    /// Returns the current scale of the image of the [`Viewer`].
+    ///
+    /// A scale of `1.0` fits the image in the [`Viewer`], following its
+    /// [`ContentFit`].
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Sets the scale of the image of the [`Viewer`].
+    ///
+    /// A scale of `1.0` fits the image in the [`Viewer`], following its
+    /// [`ContentFit`].
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
+    }
+
+    /// Returns the current offset of the view from the center of the image
+    /// of the [`Viewer`].
+    pub fn offset(&self) -> Vector {
+        self.current_offset
+    }
+
+    /// Sets the offset of the view from the center of the image of the
+    /// [`Viewer`].
+    ///
+    /// The offset is limited when drawing, so the image never leaves the
+    /// [`Viewer`].
+    pub fn set_offset(&mut self, offset: Vector) {
+        self.current_offset = offset;
+    }
+
+    /// Returns the current [`Rotation`] of the image of the [`Viewer`].
+    ///
+    /// [`Rotation`]: image::Rotation
+    pub fn rotation(&self) -> image::Rotation {
+        self.rotation
+    }
+
+    /// Sets the [`Rotation`] of the image of the [`Viewer`].
+    ///
+    /// [`Rotation`]: image::Rotation
+    pub fn set_rotation(&mut self, rotation: image::Rotation) {
+        self.rotation = rotation;
+    }
+
+    /// Rotates the image of the [`Viewer`] by 90 degrees clockwise, keeping
+    /// the same part of it in view.
+    pub fn rotate_clockwise(&mut self) {
+        self.rotation = self.rotation.clockwise();
+        self.current_offset =
+            Vector::new(-self.current_offset.y, self.current_offset.x);
+    }
+
+    /// Rotates the image of the [`Viewer`] by 90 degrees counterclockwise,
+    /// keeping the same part of it in view.
+    pub fn rotate_counterclockwise(&mut self) {
+        self.rotation = self.rotation.counterclockwise();
+        self.current_offset =
+            Vector::new(self.current_offset.y, -self.current_offset.x);
+    }
+
+    /// Fits the image in the [`Viewer`], following its [`ContentFit`].
+    pub fn fit(&mut self) {
+        self.scale = 1.0;
+        self.current_offset = Vector::default();
+    }
+
+    /// Shows the image of the [`Viewer`] at its actual size.
+    ///
+    /// The actual size of the image is only known once the [`Viewer`] has
+    /// processed an event; until then, this is the same as [`fit`].
+    ///
+    /// [`fit`]: Self::fit
+    pub fn actual_size(&mut self) {
+        self.scale = self.actual_scale;
+        self.current_offset = Vector::default();
+    }
+
     /// Returns the current offset of the [`State`], given the bounds
     /// of the [`Viewer`] and its image.
-    fn offset(&self, bounds: Rectangle, image_size: Size) -> Vector {
+    fn clamped_offset(&self, bounds: Rectangle, image_size: Size) -> Vector {
         let hidden_width =
             (image_size.width - bounds.width / 2.0).max(0.0).round();
 
@@ -0,0 +1,340 @@
+//! Listen to pointer events on some content.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use std::hash::Hash;
+
+/// A transparent wrapper that produces messages on pointer events.
+///
+/// A [`MouseArea`] has no appearance of its own. It lays out and draws its
+/// content as is, and only reacts to the events that its content does not
+/// capture.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{mouse_area, Text};
+/// # use iced_native::mouse;
+/// # use iced_native::renderer::Null;
+/// #
+/// # type MouseArea<'a, Message> =
+/// #     iced_native::widget::MouseArea<'a, Message, Null>;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     RowSelected,
+///     RowOpened,
+/// }
+///
+/// let mut state = mouse_area::State::new();
+/// let row = MouseArea::new(&mut state, Text::new("Invoice #42"))
+///     .on_press(Message::RowSelected)
+///     .on_double_click(Message::RowOpened)
+///     .interaction(mouse::Interaction::Pointer);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MouseArea<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    on_press: Option<Message>,
+    on_release: Option<Message>,
+    on_right_press: Option<Message>,
+    on_middle_press: Option<Message>,
+    on_double_click: Option<Message>,
+    on_enter: Option<Message>,
+    on_exit: Option<Message>,
+    on_move: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    interaction: Option<mouse::Interaction>,
+}
+
+impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
+    /// Creates a new [`MouseArea`] with the given [`State`] and content.
+    pub fn new(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        MouseArea {
+            state,
+            content: content.into(),
+            on_press: None,
+            on_release: None,
+            on_right_press: None,
+            on_middle_press: None,
+            on_double_click: None,
+            on_enter: None,
+            on_exit: None,
+            on_move: None,
+            interaction: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the left mouse button is
+    /// pressed over the [`MouseArea`].
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the left mouse button is
+    /// released over the [`MouseArea`].
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the right mouse button is
+    /// pressed over the [`MouseArea`].
+    pub fn on_right_press(mut self, message: Message) -> Self {
+        self.on_right_press = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the middle mouse button is
+    /// pressed over the [`MouseArea`].
+    pub fn on_middle_press(mut self, message: Message) -> Self {
+        self.on_middle_press = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`MouseArea`] is
+    /// double clicked.
+    ///
+    /// The message of [`on_press`](Self::on_press) is still produced for
+    /// both clicks.
+    pub fn on_double_click(mut self, message: Message) -> Self {
+        self.on_double_click = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the cursor enters the
+    /// [`MouseArea`].
+    pub fn on_enter(mut self, message: Message) -> Self {
+        self.on_enter = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the cursor leaves the
+    /// [`MouseArea`].
+    pub fn on_exit(mut self, message: Message) -> Self {
+        self.on_exit = Some(message);
+        self
+    }
+
+    /// Sets the function that will be called when the cursor moves over the
+    /// [`MouseArea`].
+    ///
+    /// It receives the position of the cursor relative to the top-left corner
+    /// of the [`MouseArea`].
+    pub fn on_move(mut self, f: impl Fn(Point) -> Message + 'a) -> Self {
+        self.on_move = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the [`mouse::Interaction`] shown when the cursor is over the
+    /// [`MouseArea`] and its content does not request one of its own.
+    pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
+        self.interaction = Some(interaction);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MouseArea<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        if let Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Touch(touch::Event::FingerMoved { .. }) = event
+        {
+            if is_mouse_over != self.state.is_hovered {
+                self.state.is_hovered = is_mouse_over;
+
+                let message = if is_mouse_over {
+                    self.on_enter.clone()
+                } else {
+                    self.on_exit.clone()
+                };
+
+                if let Some(message) = message {
+                    shell.publish(message);
+                }
+            }
+
+            if let Some(on_move) = &self.on_move {
+                if is_mouse_over {
+                    shell.publish(on_move(Point::new(
+                        cursor_position.x - bounds.x,
+                        cursor_position.y - bounds.y,
+                    )));
+                }
+            }
+
+            return status;
+        }
+
+        if status == event::Status::Captured || !is_mouse_over {
+            return status;
+        }
+
+        let messages = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let click =
+                    mouse::Click::new(cursor_position, self.state.last_click);
+
+                self.state.last_click = Some(click);
+
+                let on_double_click = match click.kind() {
+                    click::Kind::Double => self.on_double_click.clone(),
+                    _ => None,
+                };
+
+                vec![self.on_press.clone(), on_double_click]
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                vec![self.on_release.clone()]
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                vec![self.on_right_press.clone()]
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) => vec![self.on_middle_press.clone()],
+            _ => Vec::new(),
+        };
+
+        let mut status = event::Status::Ignored;
+
+        for message in messages.into_iter().flatten() {
+            shell.publish(message);
+
+            status = event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        let interaction =
+            self.content
+                .mouse_interaction(layout, cursor_position, viewport);
+
+        match self.interaction {
+            Some(custom)
+                if interaction == mouse::Interaction::Idle
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                custom
+            }
+            _ => interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout)
+    }
+}
+
+/// The local state of a [`MouseArea`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_hovered: bool,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the cursor is currently over the [`MouseArea`].
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+}
+
+impl<'a, Message, Renderer> From<MouseArea<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        mouse_area: MouseArea<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(mouse_area)
+    }
+}
@@ -343,8 +343,11 @@ where
                 let region = regions.get(pane)?;
                 let size = Size::new(region.width, region.height);
 
-                let mut node =
-                    element.layout(renderer, &layout::Limits::new(size, size));
+                let mut node = element.layout(
+                    renderer,
+                    &layout::Limits::new(size, size)
+                        .with_direction(limits.direction()),
+                );
 
                 node.move_to(Point::new(region.x, region.y));
 
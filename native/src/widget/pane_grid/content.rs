@@ -128,8 +128,11 @@ where
         if let Some(title_bar) = &self.title_bar {
             let max_size = limits.max();
 
-            let title_bar_layout = title_bar
-                .layout(renderer, &layout::Limits::new(Size::ZERO, max_size));
+            let title_bar_layout = title_bar.layout(
+                renderer,
+                &layout::Limits::new(Size::ZERO, max_size)
+                    .with_direction(limits.direction()),
+            );
 
             let title_bar_size = title_bar_layout.size();
 
@@ -141,7 +144,8 @@ where
                         max_size.width,
                         max_size.height - title_bar_size.height,
                     ),
-                ),
+                )
+                .with_direction(limits.direction()),
             );
 
             body_layout.move_to(Point::new(0.0, title_bar_size.height));
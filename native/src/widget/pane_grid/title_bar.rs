@@ -176,14 +176,19 @@ where
         let limits = limits.pad(self.padding);
         let max_size = limits.max();
 
-        let title_layout = self
-            .content
-            .layout(renderer, &layout::Limits::new(Size::ZERO, max_size));
+        let title_layout = self.content.layout(
+            renderer,
+            &layout::Limits::new(Size::ZERO, max_size)
+                .with_direction(limits.direction()),
+        );
         let title_size = title_layout.size();
 
         let mut node = if let Some(controls) = &self.controls {
-            let mut controls_layout = controls
-                .layout(renderer, &layout::Limits::new(Size::ZERO, max_size));
+            let mut controls_layout = controls.layout(
+                renderer,
+                &layout::Limits::new(Size::ZERO, max_size)
+                    .with_direction(limits.direction()),
+            );
 
             let controls_size = controls_layout.size();
             let space_before_controls = max_size.width - controls_size.width;
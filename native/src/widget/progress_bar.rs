@@ -1,16 +1,46 @@
 //! Provide progress feedback to your users.
+use crate::alignment;
+use crate::event::{self, Event};
 use crate::layout;
 use crate::renderer;
+use crate::text;
+use crate::window;
 use crate::{
-    Color, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+    Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Shell,
+    Size, Widget,
 };
 
+use std::time::{Duration, Instant};
 use std::{hash::Hash, ops::RangeInclusive};
 
+pub use crate::widget::slider::Orientation;
 pub use iced_style::progress_bar::{Style, StyleSheet};
 
+/// The time it takes for the bar of an indeterminate [`ProgressBar`] to travel
+/// across it.
+const INDETERMINATE_PERIOD: Duration = Duration::from_millis(1500);
+
+/// The length of the bar of an indeterminate [`ProgressBar`], relative to the
+/// length of the [`ProgressBar`].
+const INDETERMINATE_LENGTH: f32 = 0.3;
+
+/// The time between the frames of an indeterminate [`ProgressBar`].
+const FRAME_DURATION: Duration = Duration::from_millis(16);
+
 /// A bar that displays progress.
 ///
+/// A [`ProgressBar`] can also show a secondary [`buffer`] value, like the
+/// amount of a video that has been downloaded, and a label over the bar.
+///
+/// When the progress of a task cannot be measured, an
+/// [`indeterminate`] [`ProgressBar`] shows an animated bar instead.
+///
+/// Drawing the label of a [`ProgressBar`] needs a [`text::Renderer`], so it
+/// can only be used with renderers that support text.
+///
+/// [`buffer`]: Self::buffer
+/// [`indeterminate`]: Self::indeterminate
+///
 /// # Example
 /// ```
 /// # use iced_native::widget::ProgressBar;
@@ -24,13 +54,20 @@ pub use iced_style::progress_bar::{Style, StyleSheet};
 pub struct ProgressBar<'a> {
     range: RangeInclusive<f32>,
     value: f32,
-    width: Length,
+    buffer: Option<f32>,
+    indeterminate: Option<&'a mut State>,
+    orientation: Orientation,
+    label: Option<String>,
+    text_size: Option<u16>,
+    width: Option<Length>,
     height: Option<Length>,
     style_sheet: Box<dyn StyleSheet + 'a>,
 }
 
 impl<'a> ProgressBar<'a> {
     /// The default height of a [`ProgressBar`].
+    ///
+    /// It is its default width when it is [`Orientation::Vertical`].
     pub const DEFAULT_HEIGHT: u16 = 30;
 
     /// Creates a new [`ProgressBar`].
@@ -42,15 +79,59 @@ impl<'a> ProgressBar<'a> {
         ProgressBar {
             value: value.max(*range.start()).min(*range.end()),
             range,
-            width: Length::Fill,
+            buffer: None,
+            indeterminate: None,
+            orientation: Orientation::default(),
+            label: None,
+            text_size: None,
+            width: None,
             height: None,
             style_sheet: Default::default(),
         }
     }
 
+    /// Creates a new indeterminate [`ProgressBar`] with the given [`State`],
+    /// for tasks of unknown duration.
+    ///
+    /// Its bar keeps moving across the [`ProgressBar`] while it is shown.
+    pub fn indeterminate(state: &'a mut State) -> Self {
+        ProgressBar {
+            indeterminate: Some(state),
+            ..Self::new(0.0..=1.0, 0.0)
+        }
+    }
+
+    /// Sets the buffer value of the [`ProgressBar`], drawn behind its current
+    /// value.
+    pub fn buffer(mut self, buffer: f32) -> Self {
+        self.buffer =
+            Some(buffer.max(*self.range.start()).min(*self.range.end()));
+        self
+    }
+
+    /// Sets the [`Orientation`] of the [`ProgressBar`].
+    ///
+    /// A vertical [`ProgressBar`] fills up from the bottom.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the label drawn over the [`ProgressBar`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the text size of the label of the [`ProgressBar`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
     /// Sets the width of the [`ProgressBar`].
     pub fn width(mut self, width: Length) -> Self {
-        self.width = width;
+        self.width = Some(width);
         self
     }
 
@@ -68,18 +149,70 @@ impl<'a> ProgressBar<'a> {
         self.style_sheet = style_sheet.into();
         self
     }
+
+    /// Returns the start and the end of the bar of the [`ProgressBar`],
+    /// relative to its length.
+    fn bar(&self) -> (f32, f32) {
+        if let Some(state) = &self.indeterminate {
+            let elapsed =
+                state.elapsed().as_millis() % INDETERMINATE_PERIOD.as_millis();
+
+            let phase =
+                elapsed as f32 / INDETERMINATE_PERIOD.as_millis() as f32;
+            let start =
+                phase * (1.0 + INDETERMINATE_LENGTH) - INDETERMINATE_LENGTH;
+
+            (start.max(0.0), (start + INDETERMINATE_LENGTH).min(1.0))
+        } else {
+            (0.0, self.progress(self.value))
+        }
+    }
+
+    /// Returns the progress of the given value, from `0.0` to `1.0`.
+    fn progress(&self, value: f32) -> f32 {
+        let (range_start, range_end) = self.range.clone().into_inner();
+
+        if range_start >= range_end {
+            0.0
+        } else {
+            (value - range_start) / (range_end - range_start)
+        }
+    }
+
+    /// Returns the bounds of the part of the [`ProgressBar`] between the
+    /// given progress values.
+    fn segment(&self, bounds: Rectangle, start: f32, end: f32) -> Rectangle {
+        match self.orientation {
+            Orientation::Horizontal => Rectangle {
+                x: bounds.x + bounds.width * start,
+                width: bounds.width * (end - start),
+                ..bounds
+            },
+            Orientation::Vertical => Rectangle {
+                y: bounds.y + bounds.height * (1.0 - end),
+                height: bounds.height * (end - start),
+                ..bounds
+            },
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for ProgressBar<'a>
 where
-    Renderer: crate::Renderer,
+    Renderer: text::Renderer,
 {
     fn width(&self) -> Length {
-        self.width
+        self.width.unwrap_or(match self.orientation {
+            Orientation::Horizontal => Length::Fill,
+            Orientation::Vertical => Length::Units(Self::DEFAULT_HEIGHT),
+        })
     }
 
     fn height(&self) -> Length {
-        self.height.unwrap_or(Length::Units(Self::DEFAULT_HEIGHT))
+        self.height.unwrap_or(match self.orientation {
+            Orientation::Horizontal => Length::Units(Self::DEFAULT_HEIGHT),
+            Orientation::Vertical => Length::Fill,
+        })
     }
 
     fn layout(
@@ -88,58 +221,102 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let limits = limits
-            .width(self.width)
-            .height(self.height.unwrap_or(Length::Units(Self::DEFAULT_HEIGHT)));
+            .width(Widget::<Message, Renderer>::width(self))
+            .height(Widget::<Message, Renderer>::height(self));
 
         let size = limits.resolve(Size::ZERO);
 
         layout::Node::new(size)
     }
 
+    fn on_event(
+        &mut self,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if let Some(state) = &mut self.indeterminate {
+                state.animate(now);
+
+                shell.request_redraw(now + FRAME_DURATION);
+            }
+        }
+
+        event::Status::Ignored
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _style: &renderer::Style,
+        style: &renderer::Style,
         layout: Layout<'_>,
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
-        let (range_start, range_end) = self.range.clone().into_inner();
-
-        let active_progress_width = if range_start >= range_end {
-            0.0
-        } else {
-            bounds.width * (self.value - range_start)
-                / (range_end - range_start)
-        };
-
-        let style = self.style_sheet.style();
+        let bar_style = self.style_sheet.style();
 
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle { ..bounds },
-                border_radius: style.border_radius,
+                border_radius: bar_style.border_radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
             },
-            style.background,
+            bar_style.background,
         );
 
-        if active_progress_width > 0.0 {
+        if let Some(buffer) = self.buffer {
+            let progress = self.progress(buffer);
+
+            if progress > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: self.segment(bounds, 0.0, progress),
+                        border_radius: bar_style.border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    self.style_sheet.buffer(),
+                );
+            }
+        }
+
+        let (start, end) = self.bar();
+
+        if end > start {
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: Rectangle {
-                        width: active_progress_width,
-                        ..bounds
-                    },
-                    border_radius: style.border_radius,
+                    bounds: self.segment(bounds, start, end),
+                    border_radius: bar_style.border_radius,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
                 },
-                style.bar,
+                bar_style.bar,
             );
         }
+
+        if let Some(label) = &self.label {
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: f32::from(
+                    self.text_size.unwrap_or_else(|| renderer.default_size()),
+                ),
+                color: style.text_color,
+                font: Default::default(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -148,13 +325,47 @@ where
 
         self.width.hash(state);
         self.height.hash(state);
+        self.orientation.hash(state);
+    }
+}
+
+/// The local state of an indeterminate [`ProgressBar`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    started_at: Option<Instant>,
+    now: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Advances the animation to the given frame time.
+    fn animate(&mut self, now: Instant) {
+        if self.started_at.is_none() {
+            self.started_at = Some(now);
+        }
+
+        self.now = Some(now);
+    }
+
+    /// Returns the time elapsed since the animation started.
+    fn elapsed(&self) -> Duration {
+        match (self.started_at, self.now) {
+            (Some(started_at), Some(now)) => {
+                now.saturating_duration_since(started_at)
+            }
+            _ => Duration::ZERO,
+        }
     }
 }
 
 impl<'a, Message, Renderer> From<ProgressBar<'a>>
     for Element<'a, Message, Renderer>
 where
-    Renderer: 'a + crate::Renderer,
+    Renderer: 'a + text::Renderer,
     Message: 'a,
 {
     fn from(progress_bar: ProgressBar<'a>) -> Element<'a, Message, Renderer> {
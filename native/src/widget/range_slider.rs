@@ -0,0 +1,493 @@
+//! Display an interactive selector of a range within a range of values.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::slider::{self, Orientation, Rail, TickMark};
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+pub use iced_style::slider::{
+    Handle, HandleShape, Style, StyleSheet, TickMarks,
+};
+
+/// A bar with two handles that select the start and the end of a range within
+/// a range of values.
+///
+/// A [`RangeSlider`] behaves like a [`Slider`] with two handles, which cannot
+/// cross each other. A press on the rail moves the closest handle.
+///
+/// Once focused by a click, the last handle moved can be controlled with the
+/// keyboard, like a [`Slider`].
+///
+/// [`Slider`]: crate::widget::Slider
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::range_slider::{self, RangeSlider};
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     PriceFilterChanged((u32, u32)),
+/// }
+///
+/// let state = &mut range_slider::State::new();
+///
+/// RangeSlider::new(state, 0..=500, (50, 200), Message::PriceFilterChanged)
+///     .step(10)
+///     .tick_marks(vec![0, 100, 200, 300, 400, 500]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message> {
+    state: &'a mut State,
+    range: RangeInclusive<T>,
+    step: T,
+    values: (T, T),
+    on_change: Box<dyn Fn((T, T)) -> Message>,
+    on_release: Option<Message>,
+    width: Length,
+    height: u16,
+    orientation: Orientation,
+    tick_marks: Vec<TickMark<T>>,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, T, Message> RangeSlider<'a, T, Message>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+{
+    /// The default height of a [`RangeSlider`].
+    pub const DEFAULT_HEIGHT: u16 = 22;
+
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`RangeSlider`]
+    ///   * an inclusive range of possible values
+    ///   * the current start and end of the selected range
+    ///   * a function that will be called when a handle of the
+    ///     [`RangeSlider`] is dragged. It receives the new start and end of
+    ///     the selected range and must produce a `Message`.
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<T>,
+        values: (T, T),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn((T, T)) -> Message,
+    {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+
+        let (start, end) = (clamp(values.0), clamp(values.1));
+
+        let values = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        RangeSlider {
+            state,
+            values,
+            range,
+            step: T::from(1),
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            orientation: Orientation::default(),
+            tick_marks: Vec::new(),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    /// This is called when the mouse is released from the slider.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    ///
+    /// This is the length of its rail, which runs vertically when the
+    /// [`RangeSlider`] is [`Orientation::Vertical`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    ///
+    /// This is the thickness of its rail, which is measured horizontally when
+    /// the [`RangeSlider`] is [`Orientation::Vertical`].
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Orientation`] of the [`RangeSlider`].
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the tick marks drawn along the rail of the [`RangeSlider`].
+    pub fn tick_marks(
+        mut self,
+        tick_marks: impl IntoIterator<Item = impl Into<TickMark<T>>>,
+    ) -> Self {
+        self.tick_marks = tick_marks.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`].
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+}
+
+/// The local state of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    dragging: Option<Thumb>,
+    last_moved: Thumb,
+    is_focused: bool,
+}
+
+/// A handle of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Thumb {
+    #[default]
+    Start,
+    End,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`RangeSlider`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`RangeSlider`].
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`RangeSlider`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, T, Message> RangeSlider<'a, T, Message>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    /// Moves the given handle to a new value, without crossing the other one,
+    /// and produces the new range if it changed.
+    fn change(
+        &mut self,
+        thumb: Thumb,
+        new_value: Option<T>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let new_value = match new_value {
+            Some(new_value) => new_value,
+            None => return,
+        };
+
+        let (start, end) = self.values;
+
+        let values = match thumb {
+            Thumb::Start if new_value.into() > end.into() => (end, end),
+            Thumb::Start => (new_value, end),
+            Thumb::End if new_value.into() < start.into() => (start, start),
+            Thumb::End => (start, new_value),
+        };
+
+        if (values.0.into() - start.into()).abs() > f64::EPSILON
+            || (values.1.into() - end.into()).abs() > f64::EPSILON
+        {
+            shell.publish((self.on_change)(values));
+
+            self.values = values;
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for RangeSlider<'a, T, Message>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        match self.orientation {
+            Orientation::Horizontal => self.width,
+            Orientation::Vertical => Length::Shrink,
+        }
+    }
+
+    fn height(&self) -> Length {
+        match self.orientation {
+            Orientation::Horizontal => Length::Shrink,
+            Orientation::Vertical => self.width,
+        }
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        slider::layout(
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            self.orientation,
+            &self.tick_marks,
+            self.style_sheet.as_ref(),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let rail = Rail::new(layout, self.orientation, self.height);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_mouse_over = layout.bounds().contains(cursor_position);
+
+                self.state.is_focused = is_mouse_over;
+
+                if is_mouse_over {
+                    let progress = rail.progress(cursor_position);
+
+                    let start = f64::from(slider::progress_of(
+                        self.values.0,
+                        &self.range,
+                    ));
+                    let end = f64::from(slider::progress_of(
+                        self.values.1,
+                        &self.range,
+                    ));
+
+                    // When both handles are at the same value, the one that
+                    // can move towards the cursor is picked.
+                    let thumb = if (progress - start).abs()
+                        < (progress - end).abs()
+                        || (start == end && progress < start)
+                    {
+                        Thumb::Start
+                    } else {
+                        Thumb::End
+                    };
+
+                    let new_value =
+                        slider::value_at(progress, &self.range, self.step);
+
+                    self.change(thumb, new_value, shell);
+                    self.state.dragging = Some(thumb);
+                    self.state.last_moved = thumb;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if self.state.dragging.is_some() =>
+            {
+                self.state.dragging = None;
+
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(thumb) = self.state.dragging {
+                    let new_value = slider::value_at(
+                        rail.progress(cursor_position),
+                        &self.range,
+                        self.step,
+                    );
+
+                    self.change(thumb, new_value, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused => {
+                let thumb = self.state.last_moved;
+
+                let value = match thumb {
+                    Thumb::Start => self.values.0,
+                    Thumb::End => self.values.1,
+                };
+
+                let new_value = slider::step_value(
+                    value,
+                    key_code,
+                    rail.is_reversed(),
+                    &self.range,
+                    self.step,
+                );
+
+                if new_value.is_some() {
+                    self.change(thumb, new_value, shell);
+
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let rail = Rail::new(layout, self.orientation, self.height);
+        let is_mouse_over = layout.bounds().contains(cursor_position);
+
+        let style = if self.state.dragging.is_some() {
+            self.style_sheet.dragging()
+        } else if is_mouse_over {
+            self.style_sheet.hovered()
+        } else if self.state.is_focused {
+            self.style_sheet.focused()
+        } else {
+            self.style_sheet.active()
+        };
+
+        rail.draw(renderer, &style);
+
+        rail.draw_tick_marks(
+            renderer,
+            &self.tick_marks,
+            &self.range,
+            &style.handle,
+            self.style_sheet.tick_marks(),
+        );
+
+        // The last handle moved is drawn on top, so it can be grabbed again
+        // when both handles overlap.
+        let (start, end) = (
+            slider::progress_of(self.values.0, &self.range),
+            slider::progress_of(self.values.1, &self.range),
+        );
+
+        let (first, last) = match self.state.last_moved {
+            Thumb::Start => (end, start),
+            Thumb::End => (start, end),
+        };
+
+        rail.draw_handle(renderer, first, &style.handle);
+        rail.draw_handle(renderer, last, &style.handle);
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        if self.state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if is_mouse_over {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.orientation.hash(state);
+
+        for tick_mark in &self.tick_marks {
+            tick_mark.label.hash(state);
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<RangeSlider<'a, T, Message>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        range_slider: RangeSlider<'a, T, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(range_slider)
+    }
+}
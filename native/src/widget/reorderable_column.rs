@@ -0,0 +1,731 @@
+//! Let users reorder a column of items by dragging them.
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::window;
+use crate::{
+    Alignment, Background, Clipboard, Color, Element, Hasher, Layout, Length,
+    Padding, Point, Rectangle, Shell, Vector, Widget,
+};
+
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+pub use iced_style::reorderable_column::{Style, StyleSheet};
+
+/// The time between the frames of a [`ReorderableColumn`] while it animates.
+const FRAME_DURATION: Duration = Duration::from_millis(16);
+
+/// The time it takes, in seconds, for the gap left by a dragged item to move
+/// most of the way to its new position.
+const GAP_ANIMATION_DURATION: f32 = 0.1;
+
+/// A column of items that users can reorder by dragging their handles.
+///
+/// Every item has a drag handle on its left side. While an item is dragged,
+/// the other items move out of the way to open a gap where it would be
+/// dropped.
+///
+/// A [`ReorderableColumn`] does not reorder its items by itself. Instead, it
+/// produces a [`Reorder`] when an item is dropped in a new position, which you
+/// can [`apply`](Reorder::apply) to your own list.
+///
+/// A long [`ReorderableColumn`] can be placed inside a [`Scrollable`], which
+/// scrolls automatically while an item is dragged near its edges.
+///
+/// [`Scrollable`]: crate::widget::Scrollable
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{reorderable_column, Text};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type ReorderableColumn<'a, Message> =
+/// #     iced_native::widget::ReorderableColumn<'a, Message, Null>;
+/// #
+/// use reorderable_column::Reorder;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     SongMoved(Reorder),
+/// }
+///
+/// let songs = ["Intro", "Interlude", "Outro"];
+/// let mut state = reorderable_column::State::new();
+///
+/// let playlist = songs.iter().fold(
+///     ReorderableColumn::new(&mut state).spacing(5),
+///     |column, song| column.push(Text::new(*song)),
+/// )
+/// .on_reorder(Message::SongMoved);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ReorderableColumn<'a, Message, Renderer> {
+    state: &'a mut State,
+    spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    align_items: Alignment,
+    handle_width: u16,
+    on_reorder: Option<Box<dyn Fn(Reorder) -> Message + 'a>>,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> ReorderableColumn<'a, Message, Renderer> {
+    /// The default width of the drag handles of a [`ReorderableColumn`].
+    pub const DEFAULT_HANDLE_WIDTH: u16 = 20;
+
+    /// Creates an empty [`ReorderableColumn`] with the given [`State`].
+    pub fn new(state: &'a mut State) -> Self {
+        Self::with_children(state, Vec::new())
+    }
+
+    /// Creates a [`ReorderableColumn`] with the given [`State`] and elements.
+    pub fn with_children(
+        state: &'a mut State,
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        ReorderableColumn {
+            state,
+            spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            align_items: Alignment::Start,
+            handle_width: Self::DEFAULT_HANDLE_WIDTH,
+            on_reorder: None,
+            style_sheet: Default::default(),
+            children,
+        }
+    }
+
+    /// Sets the vertical spacing _between_ elements.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ReorderableColumn`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`ReorderableColumn`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ReorderableColumn`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`ReorderableColumn`].
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`ReorderableColumn`] in pixels.
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of the
+    /// [`ReorderableColumn`].
+    pub fn align_items(mut self, align: Alignment) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Sets the width of the drag handles of the [`ReorderableColumn`].
+    pub fn handle_width(mut self, handle_width: u16) -> Self {
+        self.handle_width = handle_width;
+        self
+    }
+
+    /// Sets the message that should be produced when an item of the
+    /// [`ReorderableColumn`] is dropped in a new position.
+    pub fn on_reorder(mut self, f: impl Fn(Reorder) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`ReorderableColumn`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Adds an element to the [`ReorderableColumn`].
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Returns the bounds of the drag handle of an item with the given bounds.
+    fn handle_bounds(&self, item_bounds: Rectangle) -> Rectangle {
+        let handle_width = f32::from(self.handle_width);
+
+        Rectangle {
+            x: item_bounds.x - handle_width,
+            width: handle_width,
+            ..item_bounds
+        }
+    }
+
+    /// Returns the index where the dragged item would be dropped, along with
+    /// the offset of every item needed to open a gap there.
+    fn drop_position(
+        &self,
+        layout: Layout<'_>,
+        drag: Drag,
+    ) -> (usize, Vec<f32>) {
+        let items: Vec<Rectangle> =
+            layout.children().map(|item| item.bounds()).collect();
+
+        let dragged = match items.get(drag.index) {
+            Some(dragged) => *dragged,
+            None => return (drag.index, vec![0.0; items.len()]),
+        };
+
+        let top = drag.pointer - drag.grab;
+        let centers: Vec<f32> = items.iter().map(Rectangle::center_y).collect();
+        let to = target(&centers, drag.index, top + dragged.height / 2.0);
+
+        let gap = dragged.height + f32::from(self.spacing);
+
+        let offsets = (0..items.len())
+            .map(|i| {
+                if drag.index < i && i <= to {
+                    -gap
+                } else if to <= i && i < drag.index {
+                    gap
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        (to, offsets)
+    }
+
+    /// Moves the other items towards their offsets, requesting a new frame
+    /// until they are done, and asks an enclosing [`Scrollable`] to scroll if
+    /// the dragged item is near its edges.
+    ///
+    /// [`Scrollable`]: crate::widget::Scrollable
+    fn update_drag(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        now: Instant,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let mut drag = match self.state.drag {
+            Some(drag) => drag,
+            None => return,
+        };
+
+        // A `Scrollable` reports a negative cursor position once the cursor
+        // leaves it. The last known position is kept in that case, so the
+        // `Scrollable` keeps scrolling.
+        if cursor_position.y >= 0.0 {
+            drag.pointer = cursor_position.y;
+        }
+
+        shell.request_auto_scroll(Point::new(cursor_position.x, drag.pointer));
+
+        let (_, targets) = self.drop_position(layout, drag);
+
+        self.state.drag = Some(drag);
+        self.state.animate(&targets, now);
+
+        let is_animating = self
+            .state
+            .offsets
+            .iter()
+            .zip(&targets)
+            .any(|(offset, target)| (target - offset).abs() > 0.5);
+
+        if is_animating {
+            shell.request_redraw(now + FRAME_DURATION);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ReorderableColumn<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        // The drag handles are laid out in the left padding of the items.
+        let padding = Padding {
+            left: self.padding.left + self.handle_width,
+            ..self.padding
+        };
+
+        layout::flex::resolve(
+            layout::flex::Axis::Vertical,
+            renderer,
+            &limits,
+            padding,
+            f32::from(self.spacing),
+            self.align_items,
+            &self.children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Some(drag) = self.state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    self.update_drag(
+                        layout,
+                        cursor_position,
+                        Instant::now(),
+                        shell,
+                    );
+
+                    return event::Status::Captured;
+                }
+                Event::Window(window::Event::RedrawRequested(now)) => {
+                    self.update_drag(layout, cursor_position, now, shell);
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    let (to, _) = self.drop_position(layout, drag);
+
+                    self.state.stop();
+
+                    if to != drag.index {
+                        if let Some(on_reorder) = &self.on_reorder {
+                            shell.publish(on_reorder(Reorder {
+                                from: drag.index,
+                                to,
+                            }));
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Escape,
+                    ..
+                }) => {
+                    self.state.stop();
+
+                    return event::Status::Captured;
+                }
+                _ => {
+                    shell.request_auto_scroll(Point::new(
+                        cursor_position.x,
+                        drag.pointer,
+                    ));
+                }
+            }
+        }
+
+        let status = self
+            .children
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let pressed = layout.children().enumerate().find(|(_, item)| {
+                self.handle_bounds(item.bounds()).contains(cursor_position)
+            });
+
+            if let Some((index, item)) = pressed {
+                self.state.drag = Some(Drag {
+                    index,
+                    grab: cursor_position.y - item.bounds().y,
+                    pointer: cursor_position.y,
+                });
+                self.state.offsets = vec![0.0; self.children.len()];
+                self.state.last_update = Some(Instant::now());
+
+                return event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        if self.state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.children
+            .iter()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                if self
+                    .handle_bounds(layout.bounds())
+                    .contains(cursor_position)
+                {
+                    mouse::Interaction::Grab
+                } else {
+                    child.widget.mouse_interaction(
+                        layout,
+                        cursor_position,
+                        viewport,
+                    )
+                }
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let column_style = self.style_sheet.style();
+        let drag = self.state.drag;
+
+        for (i, (child, layout)) in
+            self.children.iter().zip(layout.children()).enumerate()
+        {
+            if matches!(drag, Some(drag) if drag.index == i) {
+                continue;
+            }
+
+            let offset = self.state.offsets.get(i).copied().unwrap_or(0.0);
+
+            renderer.with_translation(Vector::new(0.0, offset), |renderer| {
+                draw_handle(
+                    renderer,
+                    self.handle_bounds(layout.bounds()),
+                    column_style.handle_color,
+                );
+
+                child.draw(renderer, style, layout, cursor_position, viewport);
+            });
+        }
+
+        let dragged = drag.and_then(|drag| {
+            self.children
+                .get(drag.index)
+                .zip(layout.children().nth(drag.index))
+                .map(|(child, layout)| (drag, child, layout))
+        });
+
+        if let Some((drag, child, layout)) = dragged {
+            let item_bounds = layout.bounds();
+            let handle_bounds = self.handle_bounds(item_bounds);
+
+            let translation = drag.pointer - drag.grab - item_bounds.y;
+
+            renderer.with_layer(*viewport, |renderer| {
+                renderer.with_translation(
+                    Vector::new(0.0, translation),
+                    |renderer| {
+                        if column_style.dragged_background.is_some()
+                            || column_style.dragged_border_width > 0.0
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        width: handle_bounds.width
+                                            + item_bounds.width,
+                                        ..handle_bounds
+                                    },
+                                    border_radius: column_style
+                                        .dragged_border_radius,
+                                    border_width: column_style
+                                        .dragged_border_width,
+                                    border_color: column_style
+                                        .dragged_border_color,
+                                },
+                                column_style.dragged_background.unwrap_or(
+                                    Background::Color(Color::TRANSPARENT),
+                                ),
+                            );
+                        }
+
+                        draw_handle(
+                            renderer,
+                            handle_bounds,
+                            column_style.handle_color,
+                        );
+
+                        child.draw(
+                            renderer,
+                            style,
+                            layout,
+                            cursor_position,
+                            viewport,
+                        );
+                    },
+                );
+            });
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.align_items.hash(state);
+        self.spacing.hash(state);
+        self.padding.hash(state);
+        self.handle_width.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+}
+
+/// A request to move an item of a [`ReorderableColumn`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reorder {
+    /// The index of the moved item.
+    pub from: usize,
+
+    /// The index of the item once moved.
+    pub to: usize,
+}
+
+impl Reorder {
+    /// Applies the [`Reorder`] to the given items, by removing the item at
+    /// `from` and inserting it back at `to`.
+    pub fn apply<T>(self, items: &mut Vec<T>) {
+        if self.from < items.len() && self.to < items.len() {
+            let item = items.remove(self.from);
+
+            items.insert(self.to, item);
+        }
+    }
+}
+
+/// The local state of a [`ReorderableColumn`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    drag: Option<Drag>,
+    offsets: Vec<f32>,
+    last_update: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    grab: f32,
+    pointer: f32,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether an item of the [`ReorderableColumn`] is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Moves the offsets of the items towards the given targets, based on the
+    /// time elapsed since the last update.
+    fn animate(&mut self, targets: &[f32], now: Instant) {
+        let elapsed = match self.last_update {
+            Some(last_update) => {
+                now.saturating_duration_since(last_update).as_secs_f32()
+            }
+            None => 0.0,
+        };
+
+        let progress = 1.0 - (-elapsed / GAP_ANIMATION_DURATION).exp();
+
+        self.offsets.resize(targets.len(), 0.0);
+
+        for (offset, target) in self.offsets.iter_mut().zip(targets) {
+            *offset += (target - *offset) * progress;
+        }
+
+        self.last_update = Some(now);
+    }
+
+    fn stop(&mut self) {
+        self.drag = None;
+        self.offsets.clear();
+        self.last_update = None;
+    }
+}
+
+/// Returns the index where an item would be dropped, given the vertical
+/// centers of all the items, the index of the dragged item and its current
+/// center.
+fn target(centers: &[f32], from: usize, center: f32) -> usize {
+    centers
+        .iter()
+        .enumerate()
+        .filter(|(i, item_center)| *i != from && **item_center < center)
+        .count()
+}
+
+fn draw_handle<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    let width = (bounds.width / 2.0).round();
+    let x = bounds.x + ((bounds.width - width) / 2.0).round();
+    let center_y = bounds.center_y().round();
+
+    for line in -1..=1 {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x,
+                    y: center_y + line as f32 * 4.0 - 1.0,
+                    width,
+                    height: 2.0,
+                },
+                border_radius: 1.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            color,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<ReorderableColumn<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        column: ReorderableColumn<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_counts_the_items_above_the_dragged_center() {
+        let centers = [10.0, 30.0, 50.0, 70.0];
+
+        assert_eq!(target(&centers, 0, 5.0), 0);
+        assert_eq!(target(&centers, 0, 45.0), 1);
+        assert_eq!(target(&centers, 0, 90.0), 3);
+        assert_eq!(target(&centers, 3, 20.0), 1);
+        assert_eq!(target(&centers, 2, 50.0), 2);
+    }
+
+    #[test]
+    fn apply_moves_the_item() {
+        let mut items = vec!['a', 'b', 'c', 'd'];
+
+        Reorder { from: 0, to: 2 }.apply(&mut items);
+        assert_eq!(items, ['b', 'c', 'a', 'd']);
+
+        Reorder { from: 3, to: 0 }.apply(&mut items);
+        assert_eq!(items, ['d', 'b', 'c', 'a']);
+
+        Reorder { from: 4, to: 0 }.apply(&mut items);
+        assert_eq!(items, ['d', 'b', 'c', 'a']);
+    }
+}
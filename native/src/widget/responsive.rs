@@ -0,0 +1,242 @@
+//! Build content from the space available to it.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use std::cell::{Cell, RefCell, RefMut};
+use std::hash::Hash;
+
+/// A widget that builds its content from the size available to it.
+///
+/// The content is built during layout, once the available space is known.
+/// The last size is kept in the [`State`] of the [`Responsive`] widget, which
+/// allows the content to be rebuilt without a layout when the available space
+/// has not changed.
+///
+/// The view function may be called more than once per frame, so the content
+/// it produces cannot borrow mutable state from your application.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{responsive, Text};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type Responsive<'a, Message> =
+/// #     iced_native::widget::Responsive<'a, Message, Null>;
+/// #
+/// # let mut state = responsive::State::new();
+/// let sidebar: Responsive<()> = Responsive::new(&mut state, |size| {
+///     if size.width < 600.0 {
+///         Text::new("☰").into()
+///     } else {
+///         Text::new("Inbox · Sent · Drafts").into()
+///     }
+/// });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Responsive<'a, Message, Renderer> {
+    state: &'a mut State,
+    view: Box<dyn Fn(Size) -> Element<'a, Message, Renderer> + 'a>,
+    width: Length,
+    height: Length,
+    content: RefCell<Option<(Size, Element<'a, Message, Renderer>)>>,
+}
+
+impl<'a, Message, Renderer> Responsive<'a, Message, Renderer> {
+    /// Creates a new [`Responsive`] widget with the given [`State`] and a
+    /// function that produces its content from the available [`Size`].
+    pub fn new(
+        state: &'a mut State,
+        view: impl Fn(Size) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        Responsive {
+            state,
+            view: Box::new(view),
+            width: Length::Fill,
+            height: Length::Fill,
+            content: RefCell::new(None),
+        }
+    }
+
+    /// Sets the width of the [`Responsive`] widget.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Responsive`] widget.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Returns the content for the given size, building it only if it has not
+    /// been built for that size yet.
+    fn content(
+        &self,
+        size: Size,
+    ) -> RefMut<'_, Element<'a, Message, Renderer>> {
+        let mut content = self.content.borrow_mut();
+
+        let is_outdated = match content.as_ref() {
+            Some((built_size, _)) => *built_size != size,
+            None => true,
+        };
+
+        if is_outdated {
+            *content = Some((size, (self.view)(size)));
+        }
+
+        RefMut::map(content, |content| &mut content.as_mut().unwrap().1)
+    }
+
+    fn size(&self, layout: Layout<'_>) -> Size {
+        self.state
+            .size
+            .get()
+            .unwrap_or_else(|| layout.bounds().size())
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Responsive<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let available = limits.max();
+
+        self.state.size.set(Some(available));
+
+        let content = self.content(available).layout(
+            renderer,
+            &layout::Limits::new(Size::ZERO, available)
+                .with_direction(limits.direction()),
+        );
+
+        let size = limits.resolve(content.size());
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let size = self.size(layout);
+
+        self.content(size).on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.content(self.size(layout)).mouse_interaction(
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content(self.size(layout)).draw(
+            renderer,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+
+        if let Some(size) = self.state.size.get() {
+            self.content(size).hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let size = self.size(layout);
+
+        drop(self.content(size));
+
+        self.content.get_mut().as_mut().and_then(|(_, content)| {
+            content.overlay(layout.children().next().unwrap())
+        })
+    }
+}
+
+/// The local state of a [`Responsive`] widget.
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    size: Cell<Option<Size>>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        State::default()
+    }
+}
+
+impl<'a, Message, Renderer> From<Responsive<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        responsive: Responsive<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(responsive)
+    }
+}
@@ -0,0 +1,457 @@
+//! Write rich text, made of spans with their own style.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A paragraph of rich text, made of [`Span`]s with their own style.
+///
+/// The spans of a [`RichText`] are laid out and wrapped together. A [`Span`]
+/// can have a [`link`], which is produced when the [`Span`] is pressed.
+///
+/// [`link`]: Span::link
+///
+/// # Example
+///
+/// ```
+/// # type RichText<Message> =
+/// #     iced_native::widget::RichText<Message, iced_native::renderer::Null>;
+/// # use iced_native::widget::rich_text::Span;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     OpenDocs,
+/// }
+///
+/// RichText::new()
+///     .push("Read ")
+///     .push(Span::new("the docs").underline(true).link(Message::OpenDocs))
+///     .push(Span::new(" before asking!").color([1.0, 0.0, 0.0]));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RichText<Message, Renderer: text::Renderer> {
+    spans: Vec<Span<Message, Renderer::Font>>,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+}
+
+impl<Message, Renderer: text::Renderer> RichText<Message, Renderer> {
+    /// Creates an empty [`RichText`].
+    pub fn new() -> Self {
+        Self::with_spans(Vec::new())
+    }
+
+    /// Creates a [`RichText`] with the given [`Span`]s.
+    pub fn with_spans(spans: Vec<Span<Message, Renderer::Font>>) -> Self {
+        RichText {
+            spans,
+            size: None,
+            color: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Adds a [`Span`] to the [`RichText`].
+    pub fn push(
+        mut self,
+        span: impl Into<Span<Message, Renderer::Font>>,
+    ) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Sets the size of the [`Span`]s of the [`RichText`] without their own.
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`]s of the [`RichText`] without their
+    /// own.
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Span`]s of the [`RichText`] without their
+    /// own.
+    ///
+    /// [`Font`]: Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Returns the size of the given [`Span`] of the [`RichText`].
+    fn span_size(
+        &self,
+        renderer: &Renderer,
+        span: &Span<Message, Renderer::Font>,
+    ) -> u16 {
+        span.size
+            .or(self.size)
+            .unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// Returns the [`Span`]s of the [`RichText`] as the renderer expects them,
+    /// using the given [`Color`] for spans without their own.
+    fn text_spans(
+        &self,
+        renderer: &Renderer,
+        color: Color,
+    ) -> Vec<text::Span<'_, Renderer::Font>> {
+        self.spans
+            .iter()
+            .map(|span| text::Span {
+                content: &span.content,
+                size: f32::from(self.span_size(renderer, span)),
+                color: span.color.or(self.color).unwrap_or(color),
+                font: span.font.clone().unwrap_or_else(|| self.font.clone()),
+            })
+            .collect()
+    }
+
+    /// Returns the link of the [`Span`] below the given point, if any.
+    fn link_at(&self, layout: Layout<'_>, point: Point) -> Option<&Message> {
+        self.spans.iter().zip(layout.children()).find_map(
+            |(span, span_layout)| {
+                let link = span.link.as_ref()?;
+
+                if span_layout
+                    .children()
+                    .any(|region| region.bounds().contains(point))
+                {
+                    Some(link)
+                } else {
+                    None
+                }
+            },
+        )
+    }
+}
+
+impl<Message, Renderer: text::Renderer> Default
+    for RichText<Message, Renderer>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer>
+    for RichText<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let bounds = limits.max();
+        let spans = self.text_spans(renderer, Color::BLACK);
+
+        let (width, height) = renderer.measure_spans(&spans, bounds);
+        let regions = renderer.span_regions(&spans, bounds);
+
+        let size = limits.resolve(Size::new(width, height));
+
+        // The baseline of the first line is shared by all of its spans
+        let baseline =
+            spans.iter().zip(&regions).find_map(|(span, regions)| {
+                regions.first().map(|region| {
+                    region.y
+                        + renderer.ascent(span.size as u16, span.font.clone())
+                })
+            });
+
+        // Every span is a child, with a child of its own for each region
+        let children = regions
+            .into_iter()
+            .map(|regions| {
+                layout::Node::with_children(
+                    size,
+                    regions
+                        .into_iter()
+                        .map(|region| {
+                            let mut node = layout::Node::new(region.size());
+                            node.move_to(Point::new(region.x, region.y));
+
+                            node
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let node = layout::Node::with_children(size, children);
+
+        match baseline {
+            Some(baseline) => node.with_baseline(baseline),
+            None => node,
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let position = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                cursor_position
+            }
+            Event::Touch(touch::Event::FingerPressed { position, .. }) => {
+                position
+            }
+            _ => return event::Status::Ignored,
+        };
+
+        if let Some(link) = self.link_at(layout, position) {
+            shell.publish(link.clone());
+
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        if self.link_at(layout, cursor_position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let spans = self.text_spans(renderer, style.text_color);
+
+        for (span, span_layout) in self.spans.iter().zip(layout.children()) {
+            if let Some(background) = span.background {
+                for region in span_layout.children() {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: region.bounds(),
+                            border_radius: 0.0,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        background,
+                    );
+                }
+            }
+        }
+
+        renderer.fill_spans(&spans, layout.bounds());
+
+        for ((span, text_span), span_layout) in
+            self.spans.iter().zip(&spans).zip(layout.children())
+        {
+            if !span.is_underlined && !span.is_struck_through {
+                continue;
+            }
+
+            let ascent =
+                renderer.ascent(text_span.size as u16, text_span.font.clone());
+            let thickness = (text_span.size / 14.0).max(1.0).round();
+
+            for region in span_layout.children() {
+                let bounds = region.bounds();
+                let baseline = bounds.y + ascent;
+
+                let mut lines = Vec::with_capacity(2);
+
+                if span.is_underlined {
+                    lines.push(baseline + thickness);
+                }
+
+                if span.is_struck_through {
+                    lines.push(baseline - text_span.size * 0.3);
+                }
+
+                for y in lines {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                y: y.round(),
+                                height: thickness,
+                                ..bounds
+                            },
+                            border_radius: 0.0,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        text_span.color,
+                    );
+                }
+            }
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        for span in &self.spans {
+            span.content.hash(state);
+            span.size.hash(state);
+            span.font.hash(state);
+        }
+
+        self.size.hash(state);
+        self.font.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<RichText<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        rich_text: RichText<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
+
+/// A span of [`RichText`], with its own style.
+///
+/// Any style a [`Span`] does not set is taken from its [`RichText`].
+#[derive(Debug, Clone)]
+pub struct Span<Message, Font> {
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Option<Font>,
+    background: Option<Color>,
+    is_underlined: bool,
+    is_struck_through: bool,
+    link: Option<Message>,
+}
+
+impl<Message, Font> Span<Message, Font> {
+    /// Creates a new [`Span`] with the given contents.
+    pub fn new(content: impl Into<String>) -> Self {
+        Span {
+            content: content.into(),
+            size: None,
+            color: None,
+            font: None,
+            background: None,
+            is_underlined: false,
+            is_struck_through: false,
+            link: None,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the [`Span`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Color`] highlighting the background of the [`Span`].
+    pub fn background<C: Into<Color>>(mut self, color: C) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    /// Sets whether the [`Span`] is underlined.
+    pub fn underline(mut self, is_underlined: bool) -> Self {
+        self.is_underlined = is_underlined;
+        self
+    }
+
+    /// Sets whether the [`Span`] is struck through.
+    pub fn strikethrough(mut self, is_struck_through: bool) -> Self {
+        self.is_struck_through = is_struck_through;
+        self
+    }
+
+    /// Sets the message produced when the [`Span`] is pressed.
+    pub fn link(mut self, message: Message) -> Self {
+        self.link = Some(message);
+        self
+    }
+}
+
+impl<Message, Font> From<&str> for Span<Message, Font> {
+    fn from(content: &str) -> Self {
+        Span::new(content)
+    }
+}
+
+impl<Message, Font> From<String> for Span<Message, Font> {
+    fn from(content: String) -> Self {
+        Span::new(content)
+    }
+}
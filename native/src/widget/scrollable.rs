@@ -6,17 +6,35 @@ use crate::overlay;
 use crate::renderer;
 use crate::touch;
 use crate::widget::Column;
+use crate::window;
 use crate::{
-    Alignment, Background, Clipboard, Color, Element, Hasher, Layout, Length,
-    Padding, Point, Rectangle, Shell, Size, Vector, Widget,
+    Alignment, Background, Clipboard, Color, Direction, Element, Hasher,
+    Layout, Length, Padding, Point, Rectangle, Shell, Size, Vector, Widget,
 };
 
+use std::time::{Duration, Instant};
 use std::{f32, hash::Hash, u32};
 
 pub use iced_style::scrollable::StyleSheet;
 
+/// The distance from the top and bottom edges of a [`Scrollable`] where an
+/// auto-scroll request scrolls its contents.
+const AUTO_SCROLL_DISTANCE: f32 = 30.0;
+
+/// The maximum speed, in pixels per second, at which a [`Scrollable`] scrolls
+/// automatically.
+const AUTO_SCROLL_SPEED: f32 = 1000.0;
+
+/// The time between the frames of a [`Scrollable`] while it scrolls
+/// automatically.
+const FRAME_DURATION: Duration = Duration::from_millis(16);
+
 /// A widget that can vertically display an infinite amount of content with a
 /// scrollbar.
+///
+/// A [`Scrollable`] also scrolls automatically while its contents request it
+/// with [`Shell::request_auto_scroll`], like when an item is dragged near its
+/// edges.
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer> {
     state: &'a mut State,
@@ -140,71 +158,14 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
-    fn notify_on_scroll(
-        &self,
-        bounds: Rectangle,
-        content_bounds: Rectangle,
-        shell: &mut Shell<'_, Message>,
-    ) {
-        if content_bounds.height <= bounds.height {
-            return;
-        }
-
-        if let Some(on_scroll) = &self.on_scroll {
-            shell.publish(on_scroll(
-                self.state.offset.absolute(bounds, content_bounds)
-                    / (content_bounds.height - bounds.height),
-            ));
-        }
-    }
-
-    fn scrollbar(
-        &self,
-        bounds: Rectangle,
-        content_bounds: Rectangle,
-    ) -> Option<Scrollbar> {
-        let offset = self.state.offset(bounds, content_bounds);
-
-        if content_bounds.height > bounds.height {
-            let outer_width = self.scrollbar_width.max(self.scroller_width)
-                + 2 * self.scrollbar_margin;
-
-            let outer_bounds = Rectangle {
-                x: bounds.x + bounds.width - outer_width as f32,
-                y: bounds.y,
-                width: outer_width as f32,
-                height: bounds.height,
-            };
-
-            let scrollbar_bounds = Rectangle {
-                x: bounds.x + bounds.width
-                    - f32::from(outer_width / 2 + self.scrollbar_width / 2),
-                y: bounds.y,
-                width: self.scrollbar_width as f32,
-                height: bounds.height,
-            };
-
-            let ratio = bounds.height / content_bounds.height;
-            let scroller_height = bounds.height * ratio;
-            let y_offset = offset as f32 * ratio;
-
-            let scroller_bounds = Rectangle {
-                x: bounds.x + bounds.width
-                    - f32::from(outer_width / 2 + self.scroller_width / 2),
-                y: scrollbar_bounds.y + y_offset,
-                width: self.scroller_width as f32,
-                height: scroller_height,
-            };
-
-            Some(Scrollbar {
-                outer_bounds,
-                bounds: scrollbar_bounds,
-                scroller: Scroller {
-                    bounds: scroller_bounds,
-                },
-            })
-        } else {
-            None
+    /// Returns the scrollbar of the [`Scrollable`].
+    fn scrollbar(&self) -> Properties<'_, Message> {
+        Properties {
+            scrollbar_width: self.scrollbar_width,
+            scrollbar_margin: self.scrollbar_margin,
+            scroller_width: self.scroller_width,
+            on_scroll: self.on_scroll.as_deref(),
+            style_sheet: self.style_sheet.as_ref(),
         }
     }
 }
@@ -235,12 +196,14 @@ where
         let child_limits = layout::Limits::new(
             Size::new(limits.min().width, 0.0),
             Size::new(limits.max().width, f32::INFINITY),
-        );
+        )
+        .with_direction(limits.direction());
 
         let content = self.content.layout(renderer, &child_limits);
         let size = limits.resolve(content.size());
 
         layout::Node::with_children(size, vec![content])
+            .with_direction(limits.direction())
     }
 
     fn on_event(
@@ -252,122 +215,309 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        let bounds = layout.bounds();
-        let is_mouse_over = bounds.contains(cursor_position);
-
-        let content = layout.children().next().unwrap();
-        let content_bounds = content.bounds();
-
-        let scrollbar = self.scrollbar(bounds, content_bounds);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
-
-        let event_status = {
-            let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-                Point::new(
-                    cursor_position.x,
-                    cursor_position.y
-                        + self.state.offset(bounds, content_bounds) as f32,
+        let scrollbar = Properties {
+            scrollbar_width: self.scrollbar_width,
+            scrollbar_margin: self.scrollbar_margin,
+            scroller_width: self.scroller_width,
+            on_scroll: self.on_scroll.as_deref(),
+            style_sheet: self.style_sheet.as_ref(),
+        };
+        let content = &mut self.content;
+
+        update(
+            self.state,
+            &scrollbar,
+            event,
+            layout,
+            cursor_position,
+            shell,
+            |event, layout, cursor_position, shell| {
+                content.on_event(
+                    event,
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
                 )
-            } else {
-                // TODO: Make `cursor_position` an `Option<Point>` so we can encode
-                // cursor availability.
-                // This will probably happen naturally once we add multi-window
-                // support.
-                Point::new(cursor_position.x, -1.0)
-            };
-
-            self.content.on_event(
-                event.clone(),
-                content,
-                cursor_position,
-                renderer,
-                clipboard,
-                shell,
+            },
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        mouse_interaction(
+            self.state,
+            &self.scrollbar(),
+            layout,
+            cursor_position,
+            |layout, cursor_position, viewport| {
+                self.content.mouse_interaction(
+                    layout,
+                    cursor_position,
+                    viewport,
+                )
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        draw(
+            self.state,
+            &self.scrollbar(),
+            renderer,
+            layout,
+            cursor_position,
+            |renderer, layout, cursor_position, viewport| {
+                self.content.draw(
+                    renderer,
+                    style,
+                    layout,
+                    cursor_position,
+                    viewport,
+                )
+            },
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.height.hash(state);
+        self.max_height.hash(state);
+
+        self.content.hash_layout(state)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let Self { content, state, .. } = self;
+
+        content
+            .overlay(layout.children().next().unwrap())
+            .map(|overlay| {
+                let bounds = layout.bounds();
+                let content_layout = layout.children().next().unwrap();
+                let content_bounds = content_layout.bounds();
+                let offset = state.offset(bounds, content_bounds);
+
+                overlay.translate(Vector::new(0.0, -(offset as f32)))
+            })
+    }
+}
+
+/// Processes an [`Event`] and updates the [`State`] of a scrollable area
+/// accordingly.
+///
+/// The provided [`Layout`] must contain a single child: the layout of the
+/// scrollable contents. The contents receive the event first through
+/// `update_content`, with the cursor position translated by the current
+/// scrolling offset.
+pub fn update<Message>(
+    state: &mut State,
+    properties: &Properties<'_, Message>,
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    shell: &mut Shell<'_, Message>,
+    update_content: impl FnOnce(
+        Event,
+        Layout<'_>,
+        Point,
+        &mut Shell<'_, Message>,
+    ) -> event::Status,
+) -> event::Status {
+    let on_scroll = properties.on_scroll;
+    let bounds = layout.bounds();
+    let is_mouse_over = bounds.contains(cursor_position);
+
+    let content = layout.children().next().unwrap();
+    let content_bounds = content.bounds();
+
+    // The contents are scrolled before they receive the event, so they see
+    // the cursor at its new position.
+    if let Event::Window(window::Event::RedrawRequested(now)) = event {
+        if let Some(auto_scroll) = state.auto_scroll {
+            let elapsed = now
+                .saturating_duration_since(auto_scroll.last_update)
+                .as_secs_f32();
+
+            state.scroll(auto_scroll.speed * elapsed, bounds, content_bounds);
+            state.auto_scroll = Some(AutoScroll {
+                last_update: now,
+                ..auto_scroll
+            });
+
+            notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+        }
+    }
+
+    let scrollbar = scrollbar(
+        state,
+        properties,
+        bounds,
+        content_bounds,
+        layout.direction(),
+    );
+    let is_mouse_over_scrollbar = scrollbar
+        .as_ref()
+        .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+        .unwrap_or(false);
+
+    let event_status = {
+        let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+            Point::new(
+                cursor_position.x,
+                cursor_position.y + state.offset(bounds, content_bounds) as f32,
             )
+        } else {
+            // TODO: Make `cursor_position` an `Option<Point>` so we can encode
+            // cursor availability.
+            // This will probably happen naturally once we add multi-window
+            // support.
+            Point::new(cursor_position.x, -1.0)
         };
 
-        if let event::Status::Captured = event_status {
-            return event::Status::Captured;
+        // Requests made before the contents belong to an enclosing scrollable.
+        let previous_request = shell.take_auto_scroll_request();
+
+        let status =
+            update_content(event.clone(), content, cursor_position, shell);
+
+        let request = shell.take_auto_scroll_request();
+
+        if let Some(position) = previous_request {
+            shell.request_auto_scroll(position);
         }
 
-        if is_mouse_over {
-            match event {
-                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
-                            // TODO: Configurable speed (?)
-                            self.state.scroll(y * 60.0, bounds, content_bounds);
-                        }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            self.state.scroll(y, bounds, content_bounds);
-                        }
-                    }
+        auto_scroll(state, request, &event, bounds, content_bounds, shell);
 
-                    self.notify_on_scroll(bounds, content_bounds, shell);
+        status
+    };
 
-                    return event::Status::Captured;
+    if let event::Status::Captured = event_status {
+        return event::Status::Captured;
+    }
+
+    if is_mouse_over {
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => {
+                        // TODO: Configurable speed (?)
+                        state.scroll(y * 60.0, bounds, content_bounds);
+                    }
+                    mouse::ScrollDelta::Pixels { y, .. } => {
+                        state.scroll(y, bounds, content_bounds);
+                    }
                 }
-                Event::Touch(event) => {
-                    match event {
-                        touch::Event::FingerPressed { .. } => {
-                            self.state.scroll_box_touched_at =
-                                Some(cursor_position);
-                        }
-                        touch::Event::FingerMoved { .. } => {
-                            if let Some(scroll_box_touched_at) =
-                                self.state.scroll_box_touched_at
-                            {
-                                let delta =
-                                    cursor_position.y - scroll_box_touched_at.y;
-
-                                self.state.scroll(
-                                    delta,
-                                    bounds,
-                                    content_bounds,
-                                );
-
-                                self.state.scroll_box_touched_at =
-                                    Some(cursor_position);
-
-                                self.notify_on_scroll(
-                                    bounds,
-                                    content_bounds,
-                                    shell,
-                                );
-                            }
-                        }
-                        touch::Event::FingerLifted { .. }
-                        | touch::Event::FingerLost { .. } => {
-                            self.state.scroll_box_touched_at = None;
-                        }
+
+                notify_on_scroll(
+                    state,
+                    on_scroll,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+
+                return event::Status::Captured;
+            }
+            Event::Touch(event) => {
+                match event {
+                    touch::Event::FingerPressed { .. } => {
+                        state.scroll_box_touched_at = Some(cursor_position);
                     }
+                    touch::Event::FingerMoved { .. } => {
+                        if let Some(scroll_box_touched_at) =
+                            state.scroll_box_touched_at
+                        {
+                            let delta =
+                                cursor_position.y - scroll_box_touched_at.y;
 
-                    return event::Status::Captured;
+                            state.scroll(delta, bounds, content_bounds);
+
+                            state.scroll_box_touched_at = Some(cursor_position);
+
+                            notify_on_scroll(
+                                state,
+                                on_scroll,
+                                bounds,
+                                content_bounds,
+                                shell,
+                            );
+                        }
+                    }
+                    touch::Event::FingerLifted { .. }
+                    | touch::Event::FingerLost { .. } => {
+                        state.scroll_box_touched_at = None;
+                    }
                 }
-                _ => {}
+
+                return event::Status::Captured;
             }
+            _ => {}
         }
+    }
+
+    if state.is_scroller_grabbed() {
+        match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.scroller_grabbed_at = None;
 
-        if self.state.is_scroller_grabbed() {
-            match event {
-                Event::Mouse(mouse::Event::ButtonReleased(
-                    mouse::Button::Left,
-                ))
-                | Event::Touch(touch::Event::FingerLifted { .. })
-                | Event::Touch(touch::Event::FingerLost { .. }) => {
-                    self.state.scroller_grabbed_at = None;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let (Some(scrollbar), Some(scroller_grabbed_at)) =
+                    (scrollbar, state.scroller_grabbed_at)
+                {
+                    state.scroll_to(
+                        scrollbar.scroll_percentage(
+                            scroller_grabbed_at,
+                            cursor_position,
+                        ),
+                        bounds,
+                        content_bounds,
+                    );
+
+                    notify_on_scroll(
+                        state,
+                        on_scroll,
+                        bounds,
+                        content_bounds,
+                        shell,
+                    );
 
                     return event::Status::Captured;
                 }
-                Event::Mouse(mouse::Event::CursorMoved { .. })
-                | Event::Touch(touch::Event::FingerMoved { .. }) => {
-                    if let (Some(scrollbar), Some(scroller_grabbed_at)) =
-                        (scrollbar, self.state.scroller_grabbed_at)
+            }
+            _ => {}
+        }
+    } else if is_mouse_over_scrollbar {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(scrollbar) = scrollbar {
+                    if let Some(scroller_grabbed_at) =
+                        scrollbar.grab_scroller(cursor_position)
                     {
-                        self.state.scroll_to(
+                        state.scroll_to(
                             scrollbar.scroll_percentage(
                                 scroller_grabbed_at,
                                 cursor_position,
@@ -376,110 +526,63 @@ where
                             content_bounds,
                         );
 
-                        self.notify_on_scroll(bounds, content_bounds, shell);
-
-                        return event::Status::Captured;
-                    }
-                }
-                _ => {}
-            }
-        } else if is_mouse_over_scrollbar {
-            match event {
-                Event::Mouse(mouse::Event::ButtonPressed(
-                    mouse::Button::Left,
-                ))
-                | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    if let Some(scrollbar) = scrollbar {
-                        if let Some(scroller_grabbed_at) =
-                            scrollbar.grab_scroller(cursor_position)
-                        {
-                            self.state.scroll_to(
-                                scrollbar.scroll_percentage(
-                                    scroller_grabbed_at,
-                                    cursor_position,
-                                ),
-                                bounds,
-                                content_bounds,
-                            );
+                        state.scroller_grabbed_at = Some(scroller_grabbed_at);
 
-                            self.state.scroller_grabbed_at =
-                                Some(scroller_grabbed_at);
-
-                            self.notify_on_scroll(
-                                bounds,
-                                content_bounds,
-                                shell,
-                            );
+                        notify_on_scroll(
+                            state,
+                            on_scroll,
+                            bounds,
+                            content_bounds,
+                            shell,
+                        );
 
-                            return event::Status::Captured;
-                        }
+                        return event::Status::Captured;
                     }
                 }
-                _ => {}
             }
+            _ => {}
         }
-
-        event::Status::Ignored
     }
 
-    fn mouse_interaction(
-        &self,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        _viewport: &Rectangle,
-    ) -> mouse::Interaction {
-        let bounds = layout.bounds();
-        let content_layout = layout.children().next().unwrap();
-        let content_bounds = content_layout.bounds();
-        let scrollbar = self.scrollbar(bounds, content_bounds);
-
-        let is_mouse_over = bounds.contains(cursor_position);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
-
-        if is_mouse_over_scrollbar || self.state.is_scroller_grabbed() {
-            mouse::Interaction::Idle
-        } else {
-            let offset = self.state.offset(bounds, content_bounds);
-
-            let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-                Point::new(cursor_position.x, cursor_position.y + offset as f32)
-            } else {
-                Point::new(cursor_position.x, -1.0)
-            };
-
-            self.content.mouse_interaction(
-                content_layout,
-                cursor_position,
-                &Rectangle {
-                    y: bounds.y + offset as f32,
-                    ..bounds
-                },
-            )
-        }
-    }
+    event::Status::Ignored
+}
 
-    fn draw(
-        &self,
-        renderer: &mut Renderer,
-        style: &renderer::Style,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        _viewport: &Rectangle,
-    ) {
-        let bounds = layout.bounds();
-        let content_layout = layout.children().next().unwrap();
-        let content_bounds = content_layout.bounds();
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = self.scrollbar(bounds, content_bounds);
-
-        let is_mouse_over = bounds.contains(cursor_position);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+/// Computes the current [`mouse::Interaction`] of a scrollable area.
+///
+/// The provided [`Layout`] must contain a single child: the layout of the
+/// scrollable contents.
+pub fn mouse_interaction<Message>(
+    state: &State,
+    properties: &Properties<'_, Message>,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    content_interaction: impl FnOnce(
+        Layout<'_>,
+        Point,
+        &Rectangle,
+    ) -> mouse::Interaction,
+) -> mouse::Interaction {
+    let bounds = layout.bounds();
+    let content_layout = layout.children().next().unwrap();
+    let content_bounds = content_layout.bounds();
+    let scrollbar = scrollbar(
+        state,
+        properties,
+        bounds,
+        content_bounds,
+        layout.direction(),
+    );
+
+    let is_mouse_over = bounds.contains(cursor_position);
+    let is_mouse_over_scrollbar = scrollbar
+        .as_ref()
+        .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+        .unwrap_or(false);
+
+    if is_mouse_over_scrollbar || state.is_scroller_grabbed() {
+        mouse::Interaction::Idle
+    } else {
+        let offset = state.offset(bounds, content_bounds);
 
         let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
             Point::new(cursor_position.x, cursor_position.y + offset as f32)
@@ -487,122 +590,317 @@ where
             Point::new(cursor_position.x, -1.0)
         };
 
-        if let Some(scrollbar) = scrollbar {
-            renderer.with_layer(bounds, |renderer| {
-                renderer.with_translation(
-                    Vector::new(0.0, -(offset as f32)),
-                    |renderer| {
-                        self.content.draw(
-                            renderer,
-                            style,
-                            content_layout,
-                            cursor_position,
-                            &Rectangle {
-                                y: bounds.y + offset as f32,
-                                ..bounds
-                            },
-                        );
-                    },
-                );
-            });
-
-            let style = if self.state.is_scroller_grabbed() {
-                self.style_sheet.dragging()
-            } else if is_mouse_over_scrollbar {
-                self.style_sheet.hovered()
-            } else {
-                self.style_sheet.active()
-            };
-
-            let is_scrollbar_visible =
-                style.background.is_some() || style.border_width > 0.0;
+        content_interaction(
+            content_layout,
+            cursor_position,
+            &Rectangle {
+                y: bounds.y + offset as f32,
+                ..bounds
+            },
+        )
+    }
+}
 
-            renderer.with_layer(
-                Rectangle {
-                    width: bounds.width + 2.0,
-                    height: bounds.height + 2.0,
-                    ..bounds
-                },
+/// Draws a scrollable area and its scrollbar.
+///
+/// The provided [`Layout`] must contain a single child: the layout of the
+/// scrollable contents, which are drawn by `draw_content` with the current
+/// scrolling offset applied.
+pub fn draw<Message, Renderer>(
+    state: &State,
+    properties: &Properties<'_, Message>,
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    draw_content: impl FnOnce(&mut Renderer, Layout<'_>, Point, &Rectangle),
+) where
+    Renderer: crate::Renderer,
+{
+    let bounds = layout.bounds();
+    let content_layout = layout.children().next().unwrap();
+    let content_bounds = content_layout.bounds();
+    let offset = state.offset(bounds, content_bounds);
+    let scrollbar = scrollbar(
+        state,
+        properties,
+        bounds,
+        content_bounds,
+        layout.direction(),
+    );
+
+    let is_mouse_over = bounds.contains(cursor_position);
+    let is_mouse_over_scrollbar = scrollbar
+        .as_ref()
+        .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+        .unwrap_or(false);
+
+    let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+        Point::new(cursor_position.x, cursor_position.y + offset as f32)
+    } else {
+        Point::new(cursor_position.x, -1.0)
+    };
+
+    if let Some(scrollbar) = scrollbar {
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(
+                Vector::new(0.0, -(offset as f32)),
                 |renderer| {
-                    if is_scrollbar_visible {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: scrollbar.bounds,
-                                border_radius: style.border_radius,
-                                border_width: style.border_width,
-                                border_color: style.border_color,
-                            },
-                            style.background.unwrap_or(Background::Color(
-                                Color::TRANSPARENT,
-                            )),
-                        );
-                    }
-
-                    if is_mouse_over
-                        || self.state.is_scroller_grabbed()
-                        || is_scrollbar_visible
-                    {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: scrollbar.scroller.bounds,
-                                border_radius: style.scroller.border_radius,
-                                border_width: style.scroller.border_width,
-                                border_color: style.scroller.border_color,
-                            },
-                            style.scroller.color,
-                        );
-                    }
+                    draw_content(
+                        renderer,
+                        content_layout,
+                        cursor_position,
+                        &Rectangle {
+                            y: bounds.y + offset as f32,
+                            ..bounds
+                        },
+                    );
                 },
             );
+        });
+
+        let style = if state.is_scroller_grabbed() {
+            properties.style_sheet.dragging()
+        } else if is_mouse_over_scrollbar {
+            properties.style_sheet.hovered()
         } else {
-            self.content.draw(
-                renderer,
-                style,
-                content_layout,
-                cursor_position,
-                &Rectangle {
-                    y: bounds.y + offset as f32,
-                    ..bounds
-                },
-            );
+            properties.style_sheet.active()
+        };
+
+        let is_scrollbar_visible =
+            style.background.is_some() || style.border_width > 0.0;
+
+        renderer.with_layer(
+            Rectangle {
+                width: bounds.width + 2.0,
+                height: bounds.height + 2.0,
+                ..bounds
+            },
+            |renderer| {
+                if is_scrollbar_visible {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: scrollbar.bounds,
+                            border_radius: style.border_radius,
+                            border_width: style.border_width,
+                            border_color: style.border_color,
+                        },
+                        style
+                            .background
+                            .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                    );
+                }
+
+                if is_mouse_over
+                    || state.is_scroller_grabbed()
+                    || is_scrollbar_visible
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: scrollbar.scroller.bounds,
+                            border_radius: style.scroller.border_radius,
+                            border_width: style.scroller.border_width,
+                            border_color: style.scroller.border_color,
+                        },
+                        style.scroller.color,
+                    );
+                }
+            },
+        );
+    } else {
+        draw_content(
+            renderer,
+            content_layout,
+            cursor_position,
+            &Rectangle {
+                y: bounds.y + offset as f32,
+                ..bounds
+            },
+        );
+    }
+}
+
+/// Starts, continues or stops scrolling automatically, given the auto-scroll
+/// request of the contents.
+///
+/// A request that cannot be fulfilled is handed over to the enclosing
+/// scrollable, if any.
+fn auto_scroll<Message>(
+    state: &mut State,
+    request: Option<Point>,
+    event: &Event,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    shell: &mut Shell<'_, Message>,
+) {
+    let position = match request {
+        Some(position) => position,
+        None => {
+            state.auto_scroll = None;
+            return;
         }
+    };
+
+    let offset = state.offset(bounds, content_bounds) as f32;
+    let max_offset = (content_bounds.height - bounds.height).max(0.0);
+
+    let y = position.y - offset;
+    let top = bounds.y + AUTO_SCROLL_DISTANCE;
+    let bottom = bounds.y + bounds.height - AUTO_SCROLL_DISTANCE;
+
+    let proximity = if y < top {
+        (top - y).min(AUTO_SCROLL_DISTANCE)
+    } else if y > bottom {
+        -(y - bottom).min(AUTO_SCROLL_DISTANCE)
+    } else {
+        0.0
+    };
+
+    let can_scroll = (proximity > 0.0 && offset > 0.0)
+        || (proximity < 0.0 && offset < max_offset.floor());
+
+    if !can_scroll {
+        state.auto_scroll = None;
+        shell.request_auto_scroll(Point::new(position.x, y));
+
+        return;
     }
 
-    fn hash_layout(&self, state: &mut Hasher) {
-        struct Marker;
-        std::any::TypeId::of::<Marker>().hash(state);
+    let now = match event {
+        Event::Window(window::Event::RedrawRequested(now)) => *now,
+        _ => Instant::now(),
+    };
 
-        self.height.hash(state);
-        self.max_height.hash(state);
+    state.auto_scroll = Some(AutoScroll {
+        speed: proximity / AUTO_SCROLL_DISTANCE * AUTO_SCROLL_SPEED,
+        last_update: state
+            .auto_scroll
+            .map(|auto_scroll| auto_scroll.last_update)
+            .unwrap_or(now),
+    });
 
-        self.content.hash_layout(state)
+    shell.request_redraw(now + FRAME_DURATION);
+}
+
+fn notify_on_scroll<Message>(
+    state: &State,
+    on_scroll: Option<&dyn Fn(f32) -> Message>,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    shell: &mut Shell<'_, Message>,
+) {
+    if content_bounds.height <= bounds.height {
+        return;
     }
 
-    fn overlay(
-        &mut self,
-        layout: Layout<'_>,
-    ) -> Option<overlay::Element<'_, Message, Renderer>> {
-        let Self { content, state, .. } = self;
+    if let Some(on_scroll) = on_scroll {
+        shell.publish(on_scroll(
+            state.offset.absolute(bounds, content_bounds)
+                / (content_bounds.height - bounds.height),
+        ));
+    }
+}
 
-        content
-            .overlay(layout.children().next().unwrap())
-            .map(|overlay| {
-                let bounds = layout.bounds();
-                let content_layout = layout.children().next().unwrap();
-                let content_bounds = content_layout.bounds();
-                let offset = state.offset(bounds, content_bounds);
+fn scrollbar<Message>(
+    state: &State,
+    properties: &Properties<'_, Message>,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    direction: Direction,
+) -> Option<Scrollbar> {
+    let offset = state.offset(bounds, content_bounds);
+
+    let Properties {
+        scrollbar_width,
+        scrollbar_margin,
+        scroller_width,
+        ..
+    } = *properties;
+
+    if content_bounds.height > bounds.height {
+        let outer_width =
+            scrollbar_width.max(scroller_width) + 2 * scrollbar_margin;
+
+        let outer_bounds = Rectangle {
+            x: bounds.x + bounds.width - outer_width as f32,
+            y: bounds.y,
+            width: outer_width as f32,
+            height: bounds.height,
+        };
 
-                overlay.translate(Vector::new(0.0, -(offset as f32)))
-            })
+        let scrollbar_bounds = Rectangle {
+            x: bounds.x + bounds.width
+                - f32::from(outer_width / 2 + scrollbar_width / 2),
+            y: bounds.y,
+            width: scrollbar_width as f32,
+            height: bounds.height,
+        };
+
+        let ratio = bounds.height / content_bounds.height;
+        let scroller_height = bounds.height * ratio;
+        let y_offset = offset as f32 * ratio;
+
+        let scroller_bounds = Rectangle {
+            x: bounds.x + bounds.width
+                - f32::from(outer_width / 2 + scroller_width / 2),
+            y: scrollbar_bounds.y + y_offset,
+            width: scroller_width as f32,
+            height: scroller_height,
+        };
+
+        // In a right-to-left layout, the scrollbar is placed on the left.
+        let mirror = |rectangle: Rectangle| {
+            if direction.is_right_to_left() {
+                Rectangle {
+                    x: 2.0 * bounds.x + bounds.width
+                        - rectangle.x
+                        - rectangle.width,
+                    ..rectangle
+                }
+            } else {
+                rectangle
+            }
+        };
+
+        Some(Scrollbar {
+            outer_bounds: mirror(outer_bounds),
+            bounds: mirror(scrollbar_bounds),
+            scroller: Scroller {
+                bounds: mirror(scroller_bounds),
+            },
+        })
+    } else {
+        None
     }
 }
 
+/// The scrollbar of a scrollable area, as used by [`update`],
+/// [`mouse_interaction`] and [`draw`].
+#[allow(missing_debug_implementations)]
+pub struct Properties<'a, Message> {
+    /// The width of the scrollbar.
+    pub scrollbar_width: u16,
+
+    /// The margin around the scrollbar.
+    pub scrollbar_margin: u16,
+
+    /// The width of the scroller.
+    pub scroller_width: u16,
+
+    /// The function that produces a message with the new relative offset
+    /// when the area is scrolled, if any.
+    pub on_scroll: Option<&'a dyn Fn(f32) -> Message>,
+
+    /// The style of the scrollbar.
+    pub style_sheet: &'a dyn StyleSheet,
+}
+
 /// The local state of a [`Scrollable`].
 #[derive(Debug, Clone, Copy)]
 pub struct State {
     scroller_grabbed_at: Option<f32>,
     scroll_box_touched_at: Option<Point>,
     offset: Offset,
+    auto_scroll: Option<AutoScroll>,
 }
 
 impl Default for State {
@@ -611,10 +909,18 @@ impl Default for State {
             scroller_grabbed_at: None,
             scroll_box_touched_at: None,
             offset: Offset::Absolute(0.0),
+            auto_scroll: None,
         }
     }
 }
 
+/// The automatic scrolling of a [`Scrollable`].
+#[derive(Debug, Clone, Copy)]
+struct AutoScroll {
+    speed: f32,
+    last_update: Instant,
+}
+
 /// The local state of a [`Scrollable`].
 #[derive(Debug, Clone, Copy)]
 enum Offset {
@@ -698,6 +1004,23 @@ impl State {
         self.offset.absolute(bounds, content_bounds) as u32
     }
 
+    /// Hashes the current scrolling offset of the [`State`].
+    ///
+    /// Widgets whose layout depends on the scrolling offset can use this to
+    /// invalidate their cached layout when scrolled.
+    pub(crate) fn hash_offset(&self, state: &mut Hasher) {
+        match self.offset {
+            Offset::Absolute(absolute) => {
+                0.hash(state);
+                absolute.to_bits().hash(state);
+            }
+            Offset::Relative(percentage) => {
+                1.hash(state);
+                percentage.to_bits().hash(state);
+            }
+        }
+    }
+
     /// Returns whether the scroller is currently grabbed or not.
     pub fn is_scroller_grabbed(&self) -> bool {
         self.scroller_grabbed_at.is_some()
@@ -1,10 +1,13 @@
 //! Display an interactive selector of a single value from a range of values.
 //!
 //! A [`Slider`] has some local [`State`].
+use crate::alignment;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
+use crate::text;
 use crate::touch;
 use crate::{
     Background, Clipboard, Color, Element, Hasher, Layout, Length, Point,
@@ -14,7 +17,15 @@ use crate::{
 use std::hash::Hash;
 use std::ops::RangeInclusive;
 
-pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
+pub use iced_style::slider::{
+    Handle, HandleShape, Style, StyleSheet, TickMarks,
+};
+
+/// The spacing between the tick marks of a slider and their labels.
+const TICK_LABEL_SPACING: f32 = 2.0;
+
+/// The amount of steps a slider moves when `PageUp` or `PageDown` is pressed.
+const PAGE_STEPS: f64 = 10.0;
 
 /// An horizontal bar and a handle that selects a single value from a range of
 /// values.
@@ -24,6 +35,10 @@ pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
 /// The [`Slider`] range of numeric values is generic and its step size defaults
 /// to 1 unit.
 ///
+/// Once focused by a click, a [`Slider`] can be controlled with the keyboard:
+/// the arrow keys move it by one step, `PageUp` and `PageDown` by ten steps,
+/// and `Home` and `End` move it to the start and the end of its range.
+///
 /// # Example
 /// ```
 /// # use iced_native::widget::slider::{self, Slider};
@@ -50,6 +65,8 @@ pub struct Slider<'a, T, Message> {
     on_release: Option<Message>,
     width: Length,
     height: u16,
+    orientation: Orientation,
+    tick_marks: Vec<TickMark<T>>,
     style_sheet: Box<dyn StyleSheet + 'a>,
 }
 
@@ -68,8 +85,8 @@ where
     ///   * an inclusive range of possible values
     ///   * the current value of the [`Slider`]
     ///   * a function that will be called when the [`Slider`] is dragged.
-    ///   It receives the new value of the [`Slider`] and must produce a
-    ///   `Message`.
+    ///     It receives the new value of the [`Slider`] and must produce a
+    ///     `Message`.
     pub fn new<F>(
         state: &'a mut State,
         range: RangeInclusive<T>,
@@ -100,6 +117,8 @@ where
             on_release: None,
             width: Length::Fill,
             height: Self::DEFAULT_HEIGHT,
+            orientation: Orientation::default(),
+            tick_marks: Vec::new(),
             style_sheet: Default::default(),
         }
     }
@@ -116,17 +135,38 @@ where
     }
 
     /// Sets the width of the [`Slider`].
+    ///
+    /// This is the length of its rail, which runs vertically when the
+    /// [`Slider`] is [`Orientation::Vertical`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
         self
     }
 
     /// Sets the height of the [`Slider`].
+    ///
+    /// This is the thickness of its rail, which is measured horizontally when
+    /// the [`Slider`] is [`Orientation::Vertical`].
     pub fn height(mut self, height: u16) -> Self {
         self.height = height;
         self
     }
 
+    /// Sets the [`Orientation`] of the [`Slider`].
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the tick marks drawn along the rail of the [`Slider`].
+    pub fn tick_marks(
+        mut self,
+        tick_marks: impl IntoIterator<Item = impl Into<TickMark<T>>>,
+    ) -> Self {
+        self.tick_marks = tick_marks.into_iter().map(Into::into).collect();
+        self
+    }
+
     /// Sets the style of the [`Slider`].
     pub fn style(
         mut self,
@@ -147,6 +187,7 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -154,6 +195,80 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Slider`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`Slider`].
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`Slider`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// The orientation of a [`Slider`] or a [`ProgressBar`].
+///
+/// [`ProgressBar`]: crate::widget::ProgressBar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Orientation {
+    /// The rail runs from left to right, or from right to left in a
+    /// right-to-left layout.
+    #[default]
+    Horizontal,
+
+    /// The rail runs from bottom to top.
+    Vertical,
+}
+
+/// A mark drawn along the rail of a [`Slider`] at a given value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickMark<T> {
+    /// The value of the [`TickMark`].
+    pub value: T,
+
+    /// The label of the [`TickMark`], if any.
+    pub label: Option<String>,
+}
+
+impl<T> TickMark<T> {
+    /// Creates a new [`TickMark`] without a label at the given value.
+    pub fn new(value: T) -> Self {
+        TickMark { value, label: None }
+    }
+
+    /// Sets the label of the [`TickMark`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl<T> From<T> for TickMark<T> {
+    fn from(value: T) -> Self {
+        TickMark::new(value)
+    }
+}
+
+impl<'a, T, Message> Slider<'a, T, Message>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    /// Moves the [`Slider`] to a new value and produces it if it changed.
+    fn change(&mut self, new_value: Option<T>, shell: &mut Shell<'_, Message>) {
+        if let Some(new_value) = new_value {
+            if (self.value.into() - new_value.into()).abs() > f64::EPSILON {
+                shell.publish((self.on_change)(new_value));
+
+                self.value = new_value;
+            }
+        }
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
@@ -161,27 +276,36 @@ impl<'a, T, Message, Renderer> Widget<Message, Renderer>
 where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
-    Renderer: crate::Renderer,
+    Renderer: text::Renderer,
 {
     fn width(&self) -> Length {
-        self.width
+        match self.orientation {
+            Orientation::Horizontal => self.width,
+            Orientation::Vertical => Length::Shrink,
+        }
     }
 
     fn height(&self) -> Length {
-        Length::Shrink
+        match self.orientation {
+            Orientation::Horizontal => Length::Shrink,
+            Orientation::Vertical => self.width,
+        }
     }
 
     fn layout(
         &self,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let limits =
-            limits.width(self.width).height(Length::Units(self.height));
-
-        let size = limits.resolve(Size::ZERO);
-
-        layout::Node::new(size)
+        layout(
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            self.orientation,
+            &self.tick_marks,
+            self.style_sheet.as_ref(),
+        )
     }
 
     fn on_event(
@@ -193,44 +317,24 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        let rail = Rail::new(layout, self.orientation, self.height);
         let is_dragging = self.state.is_dragging;
 
-        let mut change = || {
-            let bounds = layout.bounds();
-            let new_value = if cursor_position.x <= bounds.x {
-                *self.range.start()
-            } else if cursor_position.x >= bounds.x + bounds.width {
-                *self.range.end()
-            } else {
-                let step = self.step.into();
-                let start = (*self.range.start()).into();
-                let end = (*self.range.end()).into();
-
-                let percent = f64::from(cursor_position.x - bounds.x)
-                    / f64::from(bounds.width);
-
-                let steps = (percent * (end - start) / step).round();
-                let value = steps * step + start;
-
-                if let Some(value) = T::from_f64(value) {
-                    value
-                } else {
-                    return;
-                }
-            };
-
-            if (self.value.into() - new_value.into()).abs() > f64::EPSILON {
-                shell.publish((self.on_change)(new_value));
-
-                self.value = new_value;
-            }
-        };
-
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if layout.bounds().contains(cursor_position) {
-                    change();
+                let is_mouse_over = layout.bounds().contains(cursor_position);
+
+                self.state.is_focused = is_mouse_over;
+
+                if is_mouse_over {
+                    let new_value = value_at(
+                        rail.progress(cursor_position),
+                        &self.range,
+                        self.step,
+                    );
+
+                    self.change(new_value, shell);
                     self.state.is_dragging = true;
 
                     return event::Status::Captured;
@@ -251,7 +355,38 @@ where
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
                 if is_dragging {
-                    change();
+                    let new_value = value_at(
+                        rail.progress(cursor_position),
+                        &self.range,
+                        self.step,
+                    );
+
+                    self.change(new_value, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused => {
+                // A vertical rail is always reversed, but its value still
+                // increases with the right arrow.
+                let is_right_to_left = self.orientation
+                    == Orientation::Horizontal
+                    && rail.is_reversed();
+
+                if let Some(new_value) = step_value(
+                    self.value,
+                    key_code,
+                    is_right_to_left,
+                    &self.range,
+                    self.step,
+                ) {
+                    self.change(Some(new_value), shell);
+
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
 
                     return event::Status::Captured;
                 }
@@ -270,89 +405,33 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        let bounds = layout.bounds();
-        let is_mouse_over = bounds.contains(cursor_position);
+        let rail = Rail::new(layout, self.orientation, self.height);
+        let is_mouse_over = layout.bounds().contains(cursor_position);
 
         let style = if self.state.is_dragging {
             self.style_sheet.dragging()
         } else if is_mouse_over {
             self.style_sheet.hovered()
+        } else if self.state.is_focused {
+            self.style_sheet.focused()
         } else {
             self.style_sheet.active()
         };
 
-        let rail_y = bounds.y + (bounds.height / 2.0).round();
+        rail.draw(renderer, &style);
 
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: rail_y,
-                    width: bounds.width,
-                    height: 2.0,
-                },
-                border_radius: 0.0,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            },
-            style.rail_colors.0,
-        );
-
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: rail_y + 2.0,
-                    width: bounds.width,
-                    height: 2.0,
-                },
-                border_radius: 0.0,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            },
-            Background::Color(style.rail_colors.1),
+        rail.draw_tick_marks(
+            renderer,
+            &self.tick_marks,
+            &self.range,
+            &style.handle,
+            self.style_sheet.tick_marks(),
         );
 
-        let (handle_width, handle_height, handle_border_radius) = match style
-            .handle
-            .shape
-        {
-            HandleShape::Circle { radius } => {
-                (radius * 2.0, radius * 2.0, radius)
-            }
-            HandleShape::Rectangle {
-                width,
-                border_radius,
-            } => (f32::from(width), f32::from(bounds.height), border_radius),
-        };
-
-        let value = self.value.into() as f32;
-        let (range_start, range_end) = {
-            let (start, end) = self.range.clone().into_inner();
-
-            (start.into() as f32, end.into() as f32)
-        };
-
-        let handle_offset = if range_start >= range_end {
-            0.0
-        } else {
-            (bounds.width - handle_width) * (value - range_start)
-                / (range_end - range_start)
-        };
-
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: Rectangle {
-                    x: bounds.x + handle_offset.round(),
-                    y: rail_y - handle_height / 2.0,
-                    width: handle_width,
-                    height: handle_height,
-                },
-                border_radius: handle_border_radius,
-                border_width: style.handle.border_width,
-                border_color: style.handle.border_color,
-            },
-            style.handle.color,
+        rail.draw_handle(
+            renderer,
+            progress_of(self.value, &self.range),
+            &style.handle,
         );
     }
 
@@ -379,6 +458,13 @@ where
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.width.hash(state);
+        self.height.hash(state);
+        self.orientation.hash(state);
+        self.tick_marks.len().hash(state);
+
+        for tick_mark in &self.tick_marks {
+            tick_mark.label.hash(state);
+        }
     }
 }
 
@@ -387,9 +473,469 @@ impl<'a, T, Message, Renderer> From<Slider<'a, T, Message>>
 where
     T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
     Message: 'a + Clone,
-    Renderer: 'a + crate::Renderer,
+    Renderer: 'a + text::Renderer,
 {
     fn from(slider: Slider<'a, T, Message>) -> Element<'a, Message, Renderer> {
         Element::new(slider)
     }
 }
+
+/// Computes the layout of a slider with the given rail length and thickness.
+///
+/// The tick marks and their labels are laid out after the rail, below it or
+/// to its right.
+pub(crate) fn layout<T, Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    length: Length,
+    thickness: u16,
+    orientation: Orientation,
+    tick_marks: &[TickMark<T>],
+    style_sheet: &dyn StyleSheet,
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let mut thickness = f32::from(thickness);
+
+    if !tick_marks.is_empty() {
+        let style = style_sheet.tick_marks();
+
+        thickness += style.length;
+
+        let labels = tick_marks
+            .iter()
+            .filter_map(|tick_mark| tick_mark.label.as_deref());
+
+        let label_size = match orientation {
+            Orientation::Horizontal => labels
+                .map(|_| f32::from(style.text_size))
+                .fold(0.0, f32::max),
+            Orientation::Vertical => labels
+                .map(|label| {
+                    renderer.measure_width(
+                        label,
+                        style.text_size,
+                        Default::default(),
+                    )
+                })
+                .fold(0.0, f32::max),
+        };
+
+        if label_size > 0.0 {
+            thickness += TICK_LABEL_SPACING + label_size.ceil();
+        }
+    }
+
+    let thickness = Length::Units(thickness as u16);
+
+    let limits = match orientation {
+        Orientation::Horizontal => limits.width(length).height(thickness),
+        Orientation::Vertical => limits.width(thickness).height(length),
+    };
+
+    let size = limits.resolve(Size::ZERO);
+
+    layout::Node::new(size).with_direction(limits.direction())
+}
+
+/// The rail of a slider, along which its handles move.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Rail {
+    bounds: Rectangle,
+    orientation: Orientation,
+    is_reversed: bool,
+}
+
+impl Rail {
+    /// Creates the [`Rail`] of a slider with the given layout and thickness.
+    pub(crate) fn new(
+        layout: Layout<'_>,
+        orientation: Orientation,
+        thickness: u16,
+    ) -> Self {
+        let bounds = layout.bounds();
+        let thickness = f32::from(thickness);
+
+        // Vertical rails start at the bottom, and horizontal ones at the
+        // right in a right-to-left layout.
+        let (bounds, is_reversed) = match orientation {
+            Orientation::Horizontal => (
+                Rectangle {
+                    height: thickness,
+                    ..bounds
+                },
+                layout.direction().is_right_to_left(),
+            ),
+            Orientation::Vertical => (
+                Rectangle {
+                    width: thickness,
+                    ..bounds
+                },
+                true,
+            ),
+        };
+
+        Rail {
+            bounds,
+            orientation,
+            is_reversed,
+        }
+    }
+
+    /// Returns whether the start of the [`Rail`] is at its bottom or right.
+    pub(crate) fn is_reversed(&self) -> bool {
+        self.is_reversed
+    }
+
+    /// Returns the start and the length of the [`Rail`] along its axis,
+    /// followed by its start and its thickness across it.
+    fn axes(&self) -> (f32, f32, f32, f32) {
+        match self.orientation {
+            Orientation::Horizontal => (
+                self.bounds.x,
+                self.bounds.width,
+                self.bounds.y,
+                self.bounds.height,
+            ),
+            Orientation::Vertical => (
+                self.bounds.y,
+                self.bounds.height,
+                self.bounds.x,
+                self.bounds.width,
+            ),
+        }
+    }
+
+    /// Builds a [`Rectangle`] from coordinates along and across the [`Rail`].
+    fn rectangle(
+        &self,
+        main: f32,
+        cross: f32,
+        length: f32,
+        thickness: f32,
+    ) -> Rectangle {
+        match self.orientation {
+            Orientation::Horizontal => Rectangle {
+                x: main,
+                y: cross,
+                width: length,
+                height: thickness,
+            },
+            Orientation::Vertical => Rectangle {
+                x: cross,
+                y: main,
+                width: thickness,
+                height: length,
+            },
+        }
+    }
+
+    /// Returns the progress along the [`Rail`], from `0.0` to `1.0`, of the
+    /// given cursor position.
+    pub(crate) fn progress(&self, cursor_position: Point) -> f64 {
+        let (start, length, _, _) = self.axes();
+
+        let cursor = match self.orientation {
+            Orientation::Horizontal => cursor_position.x,
+            Orientation::Vertical => cursor_position.y,
+        };
+
+        let progress = f64::from(cursor - start) / f64::from(length);
+
+        let progress = if self.is_reversed {
+            1.0 - progress
+        } else {
+            progress
+        };
+
+        progress.clamp(0.0, 1.0)
+    }
+
+    /// Returns the length along the [`Rail`] and the thickness of a handle.
+    fn handle_size(&self, handle: &Handle) -> (f32, f32) {
+        match handle.shape {
+            HandleShape::Circle { radius } => (radius * 2.0, radius * 2.0),
+            HandleShape::Rectangle { width, .. } => {
+                (f32::from(width), self.axes().3)
+            }
+        }
+    }
+
+    /// Returns the position along the [`Rail`] of a handle at the given
+    /// progress.
+    fn handle_position(&self, progress: f32, handle: &Handle) -> f32 {
+        let (start, length, _, _) = self.axes();
+        let (handle_length, _) = self.handle_size(handle);
+
+        let offset = (length - handle_length) * progress;
+
+        let offset = if self.is_reversed {
+            length - handle_length - offset
+        } else {
+            offset
+        };
+
+        start + offset.round()
+    }
+
+    /// Returns the position across the [`Rail`] of its center line.
+    fn center(&self) -> f32 {
+        let (_, _, cross, thickness) = self.axes();
+
+        cross + (thickness / 2.0).round()
+    }
+
+    /// Draws the [`Rail`].
+    pub(crate) fn draw<Renderer>(&self, renderer: &mut Renderer, style: &Style)
+    where
+        Renderer: crate::Renderer,
+    {
+        let (start, length, _, _) = self.axes();
+        let center = self.center();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: self.rectangle(start, center, length, 2.0),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            style.rail_colors.0,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: self.rectangle(start, center + 2.0, length, 2.0),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            Background::Color(style.rail_colors.1),
+        );
+    }
+
+    /// Draws a handle at the given progress along the [`Rail`].
+    pub(crate) fn draw_handle<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        progress: f32,
+        handle: &Handle,
+    ) where
+        Renderer: crate::Renderer,
+    {
+        let (handle_length, handle_thickness) = self.handle_size(handle);
+
+        let border_radius = match handle.shape {
+            HandleShape::Circle { radius } => radius,
+            HandleShape::Rectangle { border_radius, .. } => border_radius,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: self.rectangle(
+                    self.handle_position(progress, handle),
+                    self.center() - handle_thickness / 2.0,
+                    handle_length,
+                    handle_thickness,
+                ),
+                border_radius,
+                border_width: handle.border_width,
+                border_color: handle.border_color,
+            },
+            handle.color,
+        );
+    }
+
+    /// Draws the given tick marks, lined up with the center of a handle at
+    /// their value.
+    pub(crate) fn draw_tick_marks<T, Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        tick_marks: &[TickMark<T>],
+        range: &RangeInclusive<T>,
+        handle: &Handle,
+        style: TickMarks,
+    ) where
+        T: Copy + Into<f64>,
+        Renderer: text::Renderer,
+    {
+        let (_, _, cross, thickness) = self.axes();
+        let (handle_length, _) = self.handle_size(handle);
+
+        let mark_start = cross + thickness;
+        let label_start = mark_start + style.length + TICK_LABEL_SPACING;
+
+        for tick_mark in tick_marks {
+            let position = self
+                .handle_position(progress_of(tick_mark.value, range), handle)
+                + (handle_length / 2.0).round();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.rectangle(
+                        position - style.width / 2.0,
+                        mark_start,
+                        style.width,
+                        style.length,
+                    ),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                style.color,
+            );
+
+            if let Some(label) = &tick_mark.label {
+                let (bounds, horizontal_alignment, vertical_alignment) =
+                    match self.orientation {
+                        Orientation::Horizontal => (
+                            Rectangle {
+                                x: position,
+                                y: label_start,
+                                width: f32::INFINITY,
+                                height: f32::from(style.text_size),
+                            },
+                            alignment::Horizontal::Center,
+                            alignment::Vertical::Top,
+                        ),
+                        Orientation::Vertical => (
+                            Rectangle {
+                                x: label_start,
+                                y: position,
+                                width: f32::INFINITY,
+                                height: f32::from(style.text_size),
+                            },
+                            alignment::Horizontal::Left,
+                            alignment::Vertical::Center,
+                        ),
+                    };
+
+                renderer.fill_text(text::Text {
+                    content: label,
+                    bounds,
+                    size: f32::from(style.text_size),
+                    color: style.text_color,
+                    font: Default::default(),
+                    horizontal_alignment,
+                    vertical_alignment,
+                });
+            }
+        }
+    }
+}
+
+/// Returns the progress, from `0.0` to `1.0`, of a value in a range.
+pub(crate) fn progress_of<T>(value: T, range: &RangeInclusive<T>) -> f32
+where
+    T: Copy + Into<f64>,
+{
+    let value = value.into() as f32;
+    let start = (*range.start()).into() as f32;
+    let end = (*range.end()).into() as f32;
+
+    if start >= end {
+        0.0
+    } else {
+        (value - start) / (end - start)
+    }
+}
+
+/// Returns the value of a range at the given progress, snapped to the given
+/// step.
+pub(crate) fn value_at<T>(
+    progress: f64,
+    range: &RangeInclusive<T>,
+    step: T,
+) -> Option<T>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    if progress <= 0.0 {
+        Some(*range.start())
+    } else if progress >= 1.0 {
+        Some(*range.end())
+    } else {
+        let step = step.into();
+        let start = (*range.start()).into();
+        let end = (*range.end()).into();
+
+        let steps = (progress * (end - start) / step).round();
+
+        T::from_f64(steps * step + start)
+    }
+}
+
+/// Returns the value that results from pressing the given key on a focused
+/// slider, if the key controls it.
+///
+/// The arrow keys move the value by one step, `PageUp` and `PageDown` by ten
+/// steps, and `Home` and `End` move it to the start and the end of the range.
+/// `Left` and `Right` are swapped in a right-to-left layout.
+pub(crate) fn step_value<T>(
+    value: T,
+    key_code: keyboard::KeyCode,
+    is_right_to_left: bool,
+    range: &RangeInclusive<T>,
+    step: T,
+) -> Option<T>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    use keyboard::KeyCode;
+
+    let start = (*range.start()).into();
+    let end = (*range.end()).into();
+    let step = step.into();
+
+    let (forward, backward) = if is_right_to_left {
+        (KeyCode::Left, KeyCode::Right)
+    } else {
+        (KeyCode::Right, KeyCode::Left)
+    };
+
+    let new_value = match key_code {
+        KeyCode::Home => start,
+        KeyCode::End => end,
+        KeyCode::Up => value.into() + step,
+        KeyCode::Down => value.into() - step,
+        KeyCode::PageUp => value.into() + step * PAGE_STEPS,
+        KeyCode::PageDown => value.into() - step * PAGE_STEPS,
+        key_code if key_code == forward => value.into() + step,
+        key_code if key_code == backward => value.into() - step,
+        _ => return None,
+    };
+
+    T::from_f64(new_value.max(start).min(end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_at_snaps_to_step() {
+        assert_eq!(value_at(0.0, &(0..=100), 10), Some(0));
+        assert_eq!(value_at(0.34, &(0..=100), 10), Some(30));
+        assert_eq!(value_at(0.36, &(0..=100), 10), Some(40));
+        assert_eq!(value_at(1.2, &(0..=100), 10), Some(100));
+    }
+
+    #[test]
+    fn step_value_clamps_to_range() {
+        use keyboard::KeyCode;
+
+        let range = 0..=100;
+
+        assert_eq!(step_value(50, KeyCode::Right, false, &range, 1), Some(51));
+        assert_eq!(step_value(50, KeyCode::Right, true, &range, 1), Some(49));
+        assert_eq!(
+            step_value(95, KeyCode::PageUp, false, &range, 1),
+            Some(100)
+        );
+        assert_eq!(step_value(5, KeyCode::PageDown, false, &range, 1), Some(0));
+        assert_eq!(step_value(50, KeyCode::Home, false, &range, 1), Some(0));
+        assert_eq!(step_value(50, KeyCode::End, false, &range, 1), Some(100));
+        assert_eq!(step_value(50, KeyCode::A, false, &range, 1), None);
+    }
+}
@@ -0,0 +1,404 @@
+//! Display content on top of other content.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::{
+    Alignment, Clipboard, Element, Hasher, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A container that displays its contents on top of each other.
+///
+/// Every child of a [`Stack`] is laid out in the same bounds and drawn in the
+/// order it was pushed. Events are delivered to the topmost child first, which
+/// can capture them before they reach the children below.
+///
+/// # Example
+/// ```
+/// # use iced_native::alignment;
+/// # use iced_native::widget::Text;
+/// # use iced_native::renderer::Null;
+/// #
+/// # type Stack<'a, Message> = iced_native::widget::Stack<'a, Message, Null>;
+/// #
+/// let icon: Stack<()> = Stack::new()
+///     .push(Text::new("Inbox"))
+///     .push_aligned(
+///         Text::new("3"),
+///         alignment::Horizontal::Right,
+///         alignment::Vertical::Top,
+///     );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Stack<'a, Message, Renderer> {
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    min_width: u32,
+    min_height: u32,
+    aspect_ratio: Option<f32>,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    children: Vec<Layer<'a, Message, Renderer>>,
+}
+
+struct Layer<'a, Message, Renderer> {
+    alignment: Option<(alignment::Horizontal, alignment::Vertical)>,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer> {
+    /// Creates an empty [`Stack`].
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Stack`] with the given elements, from bottom to top.
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Stack {
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            min_width: 0,
+            min_height: 0,
+            aspect_ratio: None,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            children: children
+                .into_iter()
+                .map(|content| Layer {
+                    alignment: None,
+                    content,
+                })
+                .collect(),
+        }
+    }
+
+    /// Sets the [`Padding`] of the [`Stack`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Stack`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Stack`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Stack`].
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Stack`].
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the minimum width of the [`Stack`].
+    pub fn min_width(mut self, min_width: u32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the minimum height of the [`Stack`].
+    pub fn min_height(mut self, min_height: u32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
+    /// Sets the aspect ratio of the [`Stack`], as its width divided by its
+    /// height.
+    ///
+    /// The [`Stack`] keeps this ratio whenever its constraints allow it.
+    pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Sets the default horizontal alignment of the contents of the
+    /// [`Stack`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the default vertical alignment of the contents of the [`Stack`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Adds an [`Element`] on top of the [`Stack`], using its default
+    /// alignment.
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(Layer {
+            alignment: None,
+            content: child.into(),
+        });
+        self
+    }
+
+    /// Adds an [`Element`] on top of the [`Stack`] with the given alignment.
+    pub fn push_aligned<E>(
+        mut self,
+        child: E,
+        horizontal_alignment: alignment::Horizontal,
+        vertical_alignment: alignment::Vertical,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(Layer {
+            alignment: Some((horizontal_alignment, vertical_alignment)),
+            content: child.into(),
+        });
+        self
+    }
+
+    fn alignment(
+        &self,
+        layer: &Layer<'a, Message, Renderer>,
+    ) -> (alignment::Horizontal, alignment::Vertical) {
+        layer
+            .alignment
+            .unwrap_or((self.horizontal_alignment, self.vertical_alignment))
+    }
+}
+
+impl<'a, Message, Renderer> Default for Stack<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Stack<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .min_width(self.min_width)
+            .min_height(self.min_height)
+            .width(self.width)
+            .height(self.height);
+
+        let limits = match self.aspect_ratio {
+            Some(ratio) => limits.aspect_ratio(ratio),
+            None => limits,
+        }
+        .pad(self.padding);
+
+        let intrinsic = self.children.iter().fold(Size::ZERO, |size, layer| {
+            let child = layer.content.layout(renderer, &limits.loose());
+
+            Size::new(
+                size.width.max(child.size().width),
+                size.height.max(child.size().height),
+            )
+        });
+
+        let size = limits.resolve(intrinsic);
+        let child_limits = layout::Limits::new(Size::ZERO, size)
+            .with_direction(limits.direction());
+
+        let children = self
+            .children
+            .iter()
+            .map(|layer| {
+                let (horizontal_alignment, vertical_alignment) =
+                    self.alignment(layer);
+
+                let mut child = layer.content.layout(renderer, &child_limits);
+
+                child.move_to(Point::new(
+                    self.padding.left.into(),
+                    self.padding.top.into(),
+                ));
+                child.align(
+                    Alignment::from(horizontal_alignment),
+                    Alignment::from(vertical_alignment),
+                    size,
+                );
+
+                child
+            })
+            .collect();
+
+        layout::Node::with_children(size.pad(self.padding), children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let layouts: Vec<_> = layout.children().collect();
+
+        for (layer, layout) in self.children.iter_mut().zip(layouts).rev() {
+            let status = layer.content.on_event(
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if status == event::Status::Captured {
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        let layouts: Vec<_> = layout.children().collect();
+
+        self.children
+            .iter()
+            .zip(layouts)
+            .rev()
+            .map(|(layer, layout)| {
+                layer.content.mouse_interaction(
+                    layout,
+                    cursor_position,
+                    viewport,
+                )
+            })
+            .find(|interaction| *interaction != mouse::Interaction::Idle)
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        for (i, (layer, layout)) in
+            self.children.iter().zip(layout.children()).enumerate()
+        {
+            if i == 0 {
+                layer.content.draw(
+                    renderer,
+                    style,
+                    layout,
+                    cursor_position,
+                    viewport,
+                );
+            } else {
+                // Every layer of the stack is drawn in its own renderer layer,
+                // so the text of a child never ends up on top of the children
+                // above it.
+                renderer.with_layer(*viewport, |renderer| {
+                    layer.content.draw(
+                        renderer,
+                        style,
+                        layout,
+                        cursor_position,
+                        viewport,
+                    );
+                });
+            }
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.min_width.hash(state);
+        self.min_height.hash(state);
+        self.aspect_ratio.map(f32::to_bits).hash(state);
+        self.padding.hash(state);
+        self.horizontal_alignment.hash(state);
+        self.vertical_alignment.hash(state);
+
+        for layer in &self.children {
+            layer.alignment.hash(state);
+            layer.content.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let layouts: Vec<_> = layout.children().collect();
+
+        self.children
+            .iter_mut()
+            .zip(layouts)
+            .rev()
+            .filter_map(|(layer, layout)| layer.content.overlay(layout))
+            .next()
+    }
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        stack: Stack<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(stack)
+    }
+}
@@ -2,7 +2,11 @@
 use crate::layout;
 use crate::renderer;
 use crate::svg::{self, Handle};
-use crate::{Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::widget::image;
+use crate::{
+    Color, ContentFit, Element, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
 
 use std::hash::Hash;
 use std::path::PathBuf;
@@ -13,11 +17,20 @@ use std::path::PathBuf;
 ///
 /// [`Svg`] images can have a considerable rendering cost when resized,
 /// specially when they are complex.
+///
+/// Monochrome icons can be tinted with a [`color`], and with a different
+/// [`hover_color`] while the cursor is over them.
+///
+/// [`color`]: Self::color
+/// [`hover_color`]: Self::hover_color
 #[derive(Debug, Clone)]
 pub struct Svg {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
+    color: Option<Color>,
+    hover_color: Option<Color>,
 }
 
 impl Svg {
@@ -27,6 +40,9 @@ impl Svg {
             handle: handle.into(),
             width: Length::Fill,
             height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            color: None,
+            hover_color: None,
         }
     }
 
@@ -47,6 +63,32 @@ impl Svg {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Svg`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`Color`] the [`Svg`] is tinted with.
+    ///
+    /// The colors of the SVG are replaced with the given [`Color`], keeping
+    /// its shape and transparency. By default, the SVG keeps its own colors.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Color`] the [`Svg`] is tinted with while the cursor is
+    /// over it.
+    ///
+    /// Defaults to the [`color`](Self::color) of the [`Svg`].
+    pub fn hover_color(mut self, color: impl Into<Color>) -> Self {
+        self.hover_color = Some(color.into());
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Svg
@@ -68,22 +110,13 @@ where
     ) -> layout::Node {
         let (width, height) = renderer.dimensions(&self.handle);
 
-        let aspect_ratio = width as f32 / height as f32;
-
-        let mut size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
-
-        let viewport_aspect_ratio = size.width / size.height;
-
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
-        }
-
-        layout::Node::new(size)
+        image::layout(
+            limits,
+            Size::new(width as f32, height as f32),
+            self.width,
+            self.height,
+            self.content_fit,
+        )
     }
 
     fn draw(
@@ -91,10 +124,36 @@ where
         renderer: &mut Renderer,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        renderer.draw(self.handle.clone(), layout.bounds())
+        let (width, height) = renderer.dimensions(&self.handle);
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let color = if is_mouse_over {
+            self.hover_color.or(self.color)
+        } else {
+            self.color
+        };
+
+        let drawing_bounds = image::fitted_bounds(
+            bounds,
+            Size::new(width as f32, height as f32),
+            self.content_fit,
+        );
+
+        let render = |renderer: &mut Renderer| {
+            renderer.draw(self.handle.clone(), color, drawing_bounds)
+        };
+
+        if drawing_bounds.width > bounds.width
+            || drawing_bounds.height > bounds.height
+        {
+            renderer.with_layer(bounds, render);
+        } else {
+            render(renderer)
+        }
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -103,6 +162,7 @@ where
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
     }
 }
 
@@ -0,0 +1,1251 @@
+//! Display tabular data with sortable, resizable columns.
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::scrollable;
+use crate::{
+    Alignment, Background, Clipboard, Color, Element, Hasher, Layout, Length,
+    Padding, Point, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::cell::{Cell, RefCell};
+use std::hash::Hash;
+use std::ops::Range;
+
+pub use iced_style::table::{Style, StyleSheet};
+
+/// A widget displaying rows of data in resizable, sortable columns.
+///
+/// A [`Table`] has a sticky header and only builds the cells of the rows
+/// that intersect its viewport, which makes it suitable for large datasets.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{table, Text};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type Table<'a, Message> = iced_native::widget::Table<'a, Message, Null>;
+/// #
+/// # let mut state = table::State::new();
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Sort(usize),
+///     Select(Vec<usize>),
+/// }
+///
+/// let people = [("Alice", 31), ("Bob", 27)];
+///
+/// let table: Table<Message> = Table::new(
+///     &mut state,
+///     vec![
+///         table::Column::new(Text::new("Name")).width(200),
+///         table::Column::new(Text::new("Age")).width(80),
+///     ],
+///     people.len(),
+///     move |row, column| {
+///         let (name, age) = people[row];
+///
+///         match column {
+///             0 => Text::new(name).into(),
+///             _ => Text::new(age.to_string()).into(),
+///         }
+///     },
+/// )
+/// .sort(1, table::SortOrder::Ascending)
+/// .on_sort(Message::Sort)
+/// .on_select(Message::Select);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer> {
+    state: &'a mut State,
+    columns: Vec<Column<'a, Message, Renderer>>,
+    row_count: usize,
+    row_height: u16,
+    cell: Box<dyn Fn(usize, usize) -> Element<'a, Message, Renderer> + 'a>,
+    rows: RefCell<Rows<'a, Message, Renderer>>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    sort: Option<(usize, SortOrder)>,
+    on_sort: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    selection: &'a [usize],
+    selection_mode: SelectionMode,
+    on_select: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    scrollbar_width: u16,
+    scrollbar_margin: u16,
+    scroller_width: u16,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+    scrollbar_style_sheet: Box<dyn scrollable::StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// The default height of the rows of a [`Table`].
+    pub const DEFAULT_ROW_HEIGHT: u16 = 30;
+
+    /// The default padding of the cells of a [`Table`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    /// The distance, in pixels, from the edge of a header cell where a column
+    /// can be grabbed to be resized.
+    const RESIZE_HANDLE_WIDTH: f32 = 4.0;
+
+    /// The space reserved for the sort indicator in a sortable header cell.
+    const SORT_INDICATOR_SPACE: f32 = 14.0;
+
+    /// Creates a new [`Table`] with the given [`State`], a list of [`Column`]
+    /// definitions, the amount of rows, and a function that builds the cell at
+    /// a given row and column index.
+    pub fn new(
+        state: &'a mut State,
+        columns: Vec<Column<'a, Message, Renderer>>,
+        row_count: usize,
+        cell: impl Fn(usize, usize) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        Table {
+            state,
+            columns,
+            row_count,
+            row_height: Self::DEFAULT_ROW_HEIGHT,
+            cell: Box::new(cell),
+            rows: RefCell::new(Rows::default()),
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            sort: None,
+            on_sort: None,
+            selection: &[],
+            selection_mode: SelectionMode::Single,
+            on_select: None,
+            scrollbar_width: 10,
+            scrollbar_margin: 0,
+            scroller_width: 10,
+            style_sheet: Default::default(),
+            scrollbar_style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the height of the rows of the [`Table`], in pixels.
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn row_height(mut self, row_height: u16) -> Self {
+        self.row_height = row_height.max(1);
+        self
+    }
+
+    /// Sets the [`Padding`] of the cells of the [`Table`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the column the [`Table`] is currently sorted by and its
+    /// [`SortOrder`].
+    ///
+    /// The header of the column will display a sort indicator. The [`Table`]
+    /// does not sort any data by itself!
+    pub fn sort(mut self, column: usize, order: SortOrder) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Sets the message that should be produced when the header of a sortable
+    /// [`Column`] is clicked.
+    ///
+    /// The function receives the index of the clicked [`Column`].
+    pub fn on_sort(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the indices of the rows that are currently selected.
+    pub fn selection(mut self, selection: &'a [usize]) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets the [`SelectionMode`] of the [`Table`].
+    pub fn selection_mode(mut self, selection_mode: SelectionMode) -> Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    /// Sets the message that should be produced when the selection of the
+    /// [`Table`] changes.
+    ///
+    /// The function receives the indices of the new selected rows.
+    pub fn on_select(mut self, f: impl Fn(Vec<usize>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the scrollbar width of the [`Table`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn scrollbar_width(mut self, scrollbar_width: u16) -> Self {
+        self.scrollbar_width = scrollbar_width.max(1);
+        self
+    }
+
+    /// Sets the scrollbar margin of the [`Table`].
+    pub fn scrollbar_margin(mut self, scrollbar_margin: u16) -> Self {
+        self.scrollbar_margin = scrollbar_margin;
+        self
+    }
+
+    /// Sets the scroller width of the [`Table`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn scroller_width(mut self, scroller_width: u16) -> Self {
+        self.scroller_width = scroller_width.max(1);
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Sets the style of the scrollbar of the [`Table`].
+    pub fn scrollbar_style(
+        mut self,
+        style_sheet: impl Into<Box<dyn scrollable::StyleSheet + 'a>>,
+    ) -> Self {
+        self.scrollbar_style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Returns the scrollbar of the body of the [`Table`].
+    fn scrollbar(&self) -> scrollable::Properties<'_, Message> {
+        scrollable::Properties {
+            scrollbar_width: self.scrollbar_width,
+            scrollbar_margin: self.scrollbar_margin,
+            scroller_width: self.scroller_width,
+            on_scroll: None,
+            style_sheet: self.scrollbar_style_sheet.as_ref(),
+        }
+    }
+
+    fn column_widths(&self) -> Vec<f32> {
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                self.state
+                    .widths
+                    .get(i)
+                    .copied()
+                    .unwrap_or_else(|| f32::from(column.width))
+                    .max(f32::from(column.min_width))
+            })
+            .collect()
+    }
+
+    /// Computes the range of rows intersecting a body of the given height.
+    fn visible_range(&self, height: f32) -> Range<usize> {
+        let row_height = f32::from(self.row_height);
+
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height,
+        };
+
+        let content_bounds = Rectangle {
+            height: row_height * self.row_count as f32,
+            ..bounds
+        };
+
+        let offset =
+            self.state.scrollable.offset(bounds, content_bounds) as f32;
+
+        let end = (((offset + height) / row_height).ceil() as usize)
+            .min(self.row_count);
+        let start = ((offset / row_height) as usize).min(end);
+
+        start..end
+    }
+
+    fn build_rows(&self, range: Range<usize>) {
+        let mut rows = self.rows.borrow_mut();
+
+        if rows.range != range {
+            *rows = Rows {
+                cells: range
+                    .clone()
+                    .map(|row| {
+                        (0..self.columns.len())
+                            .map(|column| (self.cell)(row, column))
+                            .collect()
+                    })
+                    .collect(),
+                range,
+            };
+        }
+    }
+
+    fn resize_handle(
+        &self,
+        header_layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        header_layout.children().enumerate().find_map(|(i, cell)| {
+            let bounds = cell.bounds();
+            let edge = bounds.x + bounds.width;
+
+            let is_over_handle = cursor_position.y >= bounds.y
+                && cursor_position.y <= bounds.y + bounds.height
+                && (cursor_position.x - edge).abs()
+                    <= Self::RESIZE_HANDLE_WIDTH;
+
+            is_over_handle.then_some(i)
+        })
+    }
+
+    fn select(&mut self, row: usize) -> Vec<usize> {
+        let State {
+            anchor, modifiers, ..
+        } = &mut *self.state;
+
+        match self.selection_mode {
+            SelectionMode::Single => {
+                *anchor = Some(row);
+
+                vec![row]
+            }
+            SelectionMode::Multiple => match *anchor {
+                Some(start) if modifiers.shift() => {
+                    if start <= row {
+                        (start..=row).collect()
+                    } else {
+                        (row..=start).rev().collect()
+                    }
+                }
+                _ if modifiers.command() => {
+                    *anchor = Some(row);
+
+                    if self.selection.contains(&row) {
+                        self.selection
+                            .iter()
+                            .copied()
+                            .filter(|selected| *selected != row)
+                            .collect()
+                    } else {
+                        self.selection
+                            .iter()
+                            .copied()
+                            .chain(std::iter::once(row))
+                            .collect()
+                    }
+                }
+                _ => {
+                    *anchor = Some(row);
+
+                    vec![row]
+                }
+            },
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let widths = self.column_widths();
+        let total_width: f32 = widths.iter().sum();
+        let row_height = f32::from(self.row_height);
+
+        let mut header_cells: Vec<layout::Node> = self
+            .columns
+            .iter()
+            .zip(&widths)
+            .map(|(column, width)| {
+                let reserved = if column.is_sortable {
+                    Self::SORT_INDICATOR_SPACE
+                } else {
+                    0.0
+                };
+
+                let cell_limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new((width - reserved).max(0.0), f32::INFINITY),
+                )
+                .with_direction(limits.direction())
+                .pad(self.padding);
+
+                let mut content = column.header.layout(renderer, &cell_limits);
+
+                content.move_to(Point::new(
+                    f32::from(self.padding.left),
+                    f32::from(self.padding.top),
+                ));
+
+                content
+            })
+            .collect();
+
+        let header_height = header_cells
+            .iter()
+            .map(|content| {
+                content.size().height + f32::from(self.padding.vertical())
+            })
+            .fold(0.0, f32::max);
+
+        let mut x = 0.0;
+
+        for (content, width) in header_cells.iter_mut().zip(&widths) {
+            let mut cell = layout::Node::with_children(
+                Size::new(*width, header_height),
+                vec![std::mem::take(content)],
+            );
+
+            cell.move_to(Point::new(x, 0.0));
+
+            *content = cell;
+            x += width;
+        }
+
+        let size = limits.resolve(Size::new(
+            total_width,
+            header_height + row_height * self.row_count as f32,
+        ));
+
+        let row_width = size.width.max(total_width);
+        let body_height = (size.height - header_height).max(0.0);
+        let range = self.visible_range(body_height);
+
+        self.state.body_height.set(body_height);
+        self.build_rows(range.clone());
+
+        let rows = self.rows.borrow();
+
+        let row_nodes = rows
+            .cells
+            .iter()
+            .zip(range)
+            .map(|(cells, index)| {
+                let mut x = 0.0;
+
+                let cell_nodes = cells
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| {
+                        let space = Size::new(
+                            (width - f32::from(self.padding.horizontal()))
+                                .max(0.0),
+                            (row_height - f32::from(self.padding.vertical()))
+                                .max(0.0),
+                        );
+
+                        let mut content = cell.layout(
+                            renderer,
+                            &layout::Limits::new(Size::ZERO, space)
+                                .with_direction(limits.direction()),
+                        );
+
+                        content.align(
+                            Alignment::Start,
+                            Alignment::Center,
+                            space,
+                        );
+
+                        let content = content.translate(Vector::new(
+                            f32::from(self.padding.left),
+                            f32::from(self.padding.top),
+                        ));
+
+                        let mut node = layout::Node::with_children(
+                            Size::new(*width, row_height),
+                            vec![content],
+                        );
+
+                        node.move_to(Point::new(x, 0.0));
+                        x += width;
+
+                        node
+                    })
+                    .collect();
+
+                let mut row = layout::Node::with_children(
+                    Size::new(row_width, row_height),
+                    cell_nodes,
+                );
+
+                row.move_to(Point::new(0.0, index as f32 * row_height));
+
+                row
+            })
+            .collect();
+
+        let header = layout::Node::with_children(
+            Size::new(row_width, header_height),
+            header_cells,
+        );
+
+        let content = layout::Node::with_children(
+            Size::new(row_width, row_height * self.row_count as f32),
+            row_nodes,
+        );
+
+        let mut body = layout::Node::with_children(
+            Size::new(size.width, body_height),
+            vec![content],
+        );
+
+        body.move_to(Point::new(0.0, header_height));
+
+        layout::Node::with_children(size, vec![header, body])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let body_layout = children.next().unwrap();
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            self.state.modifiers = modifiers;
+        }
+
+        if let Some(resize) = self.state.resizing {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved {
+                    position, ..
+                }) => {
+                    let min_width = self
+                        .columns
+                        .get(resize.column)
+                        .map(|column| f32::from(column.min_width))
+                        .unwrap_or(0.0);
+
+                    let mut widths = self.column_widths();
+
+                    if let Some(width) = widths.get_mut(resize.column) {
+                        *width = (resize.width + position.x - resize.origin)
+                            .max(min_width);
+                    }
+
+                    self.state.widths = widths;
+
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    self.state.resizing = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        let header_status = self
+            .columns
+            .iter_mut()
+            .zip(header_layout.children())
+            .map(|(column, cell)| {
+                column.header.widget.on_event(
+                    event.clone(),
+                    cell.children().next().unwrap(),
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if header_status == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if let Some(column) =
+                self.resize_handle(header_layout, cursor_position)
+            {
+                self.state.resizing = Some(Resize {
+                    column,
+                    origin: cursor_position.x,
+                    width: self.column_widths()[column],
+                });
+
+                return event::Status::Captured;
+            }
+
+            if let Some(on_sort) = &self.on_sort {
+                let clicked = header_layout
+                    .children()
+                    .position(|cell| cell.bounds().contains(cursor_position));
+
+                if let Some(column) = clicked {
+                    if self.columns[column].is_sortable {
+                        shell.publish(on_sort(column));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+        }
+
+        let range = self.visible_range(body_layout.bounds().height);
+
+        self.build_rows(range.clone());
+
+        let rows = self.rows.get_mut();
+        let mut pressed_row = None;
+
+        let scrollbar = scrollable::Properties {
+            scrollbar_width: self.scrollbar_width,
+            scrollbar_margin: self.scrollbar_margin,
+            scroller_width: self.scroller_width,
+            on_scroll: None,
+            style_sheet: self.scrollbar_style_sheet.as_ref(),
+        };
+
+        let status = scrollable::update(
+            &mut self.state.scrollable,
+            &scrollbar,
+            event.clone(),
+            body_layout,
+            cursor_position,
+            shell,
+            |event, layout, cursor_position, shell| {
+                let status = rows
+                    .cells
+                    .iter_mut()
+                    .zip(layout.children())
+                    .flat_map(|(cells, row)| {
+                        cells.iter_mut().zip(row.children())
+                    })
+                    .map(|(cell, layout)| {
+                        cell.widget.on_event(
+                            event.clone(),
+                            layout.children().next().unwrap(),
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            shell,
+                        )
+                    })
+                    .fold(event::Status::Ignored, event::Status::merge);
+
+                if status == event::Status::Ignored {
+                    if let Event::Mouse(mouse::Event::ButtonPressed(
+                        mouse::Button::Left,
+                    ))
+                    | Event::Touch(touch::Event::FingerPressed {
+                        ..
+                    }) = event
+                    {
+                        pressed_row = layout
+                            .children()
+                            .position(|row| {
+                                row.bounds().contains(cursor_position)
+                            })
+                            .map(|i| range.start + i);
+                    }
+                }
+
+                status
+            },
+        );
+
+        // Scrolling may reveal rows that are missing from the cached layout
+        if self.visible_range(body_layout.bounds().height) != range {
+            shell.invalidate_layout();
+        }
+
+        if status == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        if let Some(row) = pressed_row {
+            if self.on_select.is_some() {
+                let selection = self.select(row);
+
+                if let Some(on_select) = &self.on_select {
+                    shell.publish(on_select(selection));
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let body_layout = children.next().unwrap();
+
+        if self.state.resizing.is_some()
+            || self.resize_handle(header_layout, cursor_position).is_some()
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        if header_layout.bounds().contains(cursor_position) {
+            return self
+                .columns
+                .iter()
+                .zip(header_layout.children())
+                .map(|(column, cell)| {
+                    let interaction = column.header.widget.mouse_interaction(
+                        cell.children().next().unwrap(),
+                        cursor_position,
+                        viewport,
+                    );
+
+                    if interaction == mouse::Interaction::Idle
+                        && column.is_sortable
+                        && self.on_sort.is_some()
+                        && cell.bounds().contains(cursor_position)
+                    {
+                        mouse::Interaction::Pointer
+                    } else {
+                        interaction
+                    }
+                })
+                .max()
+                .unwrap_or_default();
+        }
+
+        self.build_rows(self.visible_range(body_layout.bounds().height));
+
+        let rows = self.rows.borrow();
+
+        scrollable::mouse_interaction(
+            &self.state.scrollable,
+            &self.scrollbar(),
+            body_layout,
+            cursor_position,
+            |layout, cursor_position, viewport| {
+                rows.cells
+                    .iter()
+                    .zip(layout.children())
+                    .flat_map(|(cells, row)| cells.iter().zip(row.children()))
+                    .map(|(cell, layout)| {
+                        cell.widget.mouse_interaction(
+                            layout.children().next().unwrap(),
+                            cursor_position,
+                            viewport,
+                        )
+                    })
+                    .max()
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let body_layout = children.next().unwrap();
+
+        let table_style = self.style_sheet.style();
+
+        if table_style.background.is_some() || table_style.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 0.0,
+                    border_width: table_style.border_width,
+                    border_color: table_style.border_color,
+                },
+                table_style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        let range = self.visible_range(body_layout.bounds().height);
+
+        self.build_rows(range.clone());
+
+        let rows = self.rows.borrow();
+
+        scrollable::draw(
+            &self.state.scrollable,
+            &self.scrollbar(),
+            renderer,
+            body_layout,
+            cursor_position,
+            |renderer, layout, cursor_position, viewport| {
+                for ((cells, row), index) in
+                    rows.cells.iter().zip(layout.children()).zip(range)
+                {
+                    let is_selected = self.selection.contains(&index);
+
+                    let background = if is_selected {
+                        Some(table_style.selected_row_background)
+                    } else if index % 2 == 1 {
+                        table_style.alternate_row_background
+                    } else {
+                        table_style.row_background
+                    };
+
+                    if let Some(background) = background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: row.bounds(),
+                                border_radius: 0.0,
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            background,
+                        );
+                    }
+
+                    let style = renderer::Style {
+                        text_color: is_selected
+                            .then_some(table_style.selected_text_color)
+                            .flatten()
+                            .unwrap_or(style.text_color),
+                    };
+
+                    for (cell, layout) in cells.iter().zip(row.children()) {
+                        cell.draw(
+                            renderer,
+                            &style,
+                            layout.children().next().unwrap(),
+                            cursor_position,
+                            viewport,
+                        );
+                    }
+                }
+            },
+        );
+
+        let header_bounds = header_layout.bounds();
+
+        // The columns may be wider than the table, so the header is clipped
+        // like the body.
+        let header_viewport = Rectangle {
+            y: header_bounds.y,
+            height: header_bounds.height,
+            ..bounds
+        };
+
+        renderer.with_layer(header_viewport, |renderer| {
+            if let Some(background) = table_style.header_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: header_bounds,
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            let header_style = renderer::Style {
+                text_color: table_style
+                    .header_text_color
+                    .unwrap_or(style.text_color),
+            };
+
+            for (i, (column, cell)) in self
+                .columns
+                .iter()
+                .zip(header_layout.children())
+                .enumerate()
+            {
+                let cell_bounds = cell.bounds();
+
+                column.header.draw(
+                    renderer,
+                    &header_style,
+                    cell.children().next().unwrap(),
+                    cursor_position,
+                    viewport,
+                );
+
+                match self.sort {
+                    Some((sorted, order))
+                        if sorted == i && column.is_sortable =>
+                    {
+                        draw_sort_indicator(
+                            renderer,
+                            Rectangle {
+                                x: cell_bounds.x + cell_bounds.width
+                                    - Self::SORT_INDICATOR_SPACE,
+                                width: Self::SORT_INDICATOR_SPACE,
+                                ..cell_bounds
+                            },
+                            order,
+                            table_style.sort_indicator_color,
+                        );
+                    }
+                    _ => {}
+                }
+
+                if table_style.divider_width > 0.0 {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: cell_bounds.x + cell_bounds.width
+                                    - table_style.divider_width,
+                                width: table_style.divider_width,
+                                ..cell_bounds
+                            },
+                            border_radius: 0.0,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        table_style.divider_color,
+                    );
+                }
+            }
+
+            if table_style.divider_width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: header_bounds.y + header_bounds.height
+                                - table_style.divider_width,
+                            height: table_style.divider_width,
+                            ..header_bounds
+                        },
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    table_style.divider_color,
+                );
+            }
+        });
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.padding.hash(state);
+        self.row_count.hash(state);
+        self.row_height.hash(state);
+        self.state.scrollable.hash_offset(state);
+
+        for (column, width) in self.columns.iter().zip(self.column_widths()) {
+            width.to_bits().hash(state);
+            column.is_sortable.hash(state);
+            column.header.hash_layout(state);
+        }
+
+        // The rows visible in the latest layout are the ones laid out again
+        self.build_rows(self.visible_range(self.state.body_height.get()));
+
+        for cells in &self.rows.borrow().cells {
+            for cell in cells {
+                cell.hash_layout(state);
+            }
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let body_layout = children.next().unwrap();
+
+        let bounds = body_layout.bounds();
+        let content_layout = body_layout.children().next().unwrap();
+        let offset = self
+            .state
+            .scrollable
+            .offset(bounds, content_layout.bounds());
+
+        self.build_rows(self.visible_range(bounds.height));
+
+        let Self { columns, rows, .. } = self;
+
+        let header_overlay = columns
+            .iter_mut()
+            .zip(header_layout.children())
+            .filter_map(|(column, cell)| {
+                column.header.overlay(cell.children().next().unwrap())
+            })
+            .next();
+
+        if header_overlay.is_some() {
+            return header_overlay;
+        }
+
+        rows.get_mut()
+            .cells
+            .iter_mut()
+            .zip(content_layout.children())
+            .flat_map(|(cells, row)| cells.iter_mut().zip(row.children()))
+            .filter_map(|(cell, layout)| {
+                cell.overlay(layout.children().next().unwrap())
+            })
+            .next()
+            .map(|overlay| {
+                overlay.translate(Vector::new(0.0, -(offset as f32)))
+            })
+    }
+}
+
+/// The definition of a column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<'a, Message, Renderer> {
+    header: Element<'a, Message, Renderer>,
+    width: u16,
+    min_width: u16,
+    is_sortable: bool,
+}
+
+impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
+    /// Creates a new [`Column`] with the given header content.
+    pub fn new(header: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Column {
+            header: header.into(),
+            width: 100,
+            min_width: 20,
+            is_sortable: true,
+        }
+    }
+
+    /// Sets the initial width of the [`Column`], in pixels.
+    ///
+    /// Once the user resizes the [`Column`], its width is kept in the
+    /// [`State`] of the [`Table`].
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the minimum width the [`Column`] can be resized to, in pixels.
+    pub fn min_width(mut self, min_width: u16) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets whether clicking the header of the [`Column`] sorts the
+    /// [`Table`] or not.
+    ///
+    /// Columns are sortable by default.
+    pub fn sortable(mut self, is_sortable: bool) -> Self {
+        self.is_sortable = is_sortable;
+        self
+    }
+}
+
+/// The order in which a [`Table`] is sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// From the smallest value to the largest.
+    Ascending,
+
+    /// From the largest value to the smallest.
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the opposite [`SortOrder`].
+    pub fn reverse(self) -> Self {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+}
+
+/// The way the rows of a [`Table`] can be selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelectionMode {
+    /// A single row can be selected at a time.
+    Single,
+
+    /// Many rows can be selected at once.
+    ///
+    /// Holding the command key toggles the clicked row, while holding the
+    /// shift key selects the range between the last clicked row and the
+    /// clicked one.
+    Multiple,
+}
+
+/// The local state of a [`Table`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+    widths: Vec<f32>,
+    resizing: Option<Resize>,
+    anchor: Option<usize>,
+    modifiers: keyboard::Modifiers,
+    body_height: Cell<f32>,
+}
+
+impl State {
+    /// Creates a new [`State`] with the scrollbar located at the top.
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the width of the column at the given index, if it has been
+    /// resized by the user.
+    pub fn column_width(&self, column: usize) -> Option<f32> {
+        self.widths.get(column).copied()
+    }
+
+    /// Returns whether a column is currently being resized or not.
+    pub fn is_resizing(&self) -> bool {
+        self.resizing.is_some()
+    }
+
+    /// Snaps the scroll position to a relative amount.
+    ///
+    /// `0` represents scrollbar at the top, while `1` represents scrollbar at
+    /// the bottom.
+    pub fn snap_to(&mut self, percentage: f32) {
+        self.scrollable.snap_to(percentage);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resize {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+struct Rows<'a, Message, Renderer> {
+    range: Range<usize>,
+    cells: Vec<Vec<Element<'a, Message, Renderer>>>,
+}
+
+impl<'a, Message, Renderer> Default for Rows<'a, Message, Renderer> {
+    fn default() -> Self {
+        Rows {
+            range: 0..0,
+            cells: Vec::new(),
+        }
+    }
+}
+
+/// Draws a small triangle pointing in the direction of the [`SortOrder`],
+/// centered in the given bounds.
+fn draw_sort_indicator<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    order: SortOrder,
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    const STEPS: usize = 4;
+    const STEP_HEIGHT: f32 = 1.5;
+
+    let center_x = bounds.center_x();
+    let top = bounds.center_y() - STEPS as f32 * STEP_HEIGHT / 2.0;
+
+    for step in 0..STEPS {
+        let width = match order {
+            SortOrder::Ascending => 2.0 * (step + 1) as f32,
+            SortOrder::Descending => 2.0 * (STEPS - step) as f32,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center_x - width / 2.0,
+                    y: top + step as f32 * STEP_HEIGHT,
+                    width,
+                    height: STEP_HEIGHT,
+                },
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            color,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        table: Table<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(table)
+    }
+}
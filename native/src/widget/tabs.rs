@@ -0,0 +1,857 @@
+//! Switch between views using a bar of tabs.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::{
+    Background, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
+    Point, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::hash::Hash;
+
+pub use iced_style::tabs::{Style, StyleSheet};
+
+/// A bar of [`Tab`]s on top of the content of the active one.
+///
+/// When the tabs do not fit in the bar, it can be scrolled with the mouse
+/// wheel or with the buttons displayed at its end.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{tabs, Text};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type Tabs<'a, Message> = iced_native::widget::Tabs<'a, Message, Null>;
+/// #
+/// # let mut state = tabs::State::new();
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TabSelected(usize),
+///     TabClosed(usize),
+///     TabMoved(usize, usize),
+/// }
+///
+/// let tabs: Tabs<Message> = Tabs::new(
+///     &mut state,
+///     vec![tabs::Tab::new("main.rs"), tabs::Tab::new("lib.rs")],
+///     0,
+///     Text::new("fn main() {}"),
+///     Message::TabSelected,
+/// )
+/// .on_close(Message::TabClosed)
+/// .on_reorder(Message::TabMoved);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message, Renderer: text::Renderer> {
+    state: &'a mut State,
+    tabs: Vec<Tab>,
+    active: usize,
+    content: Element<'a, Message, Renderer>,
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    icon_font: Renderer::Font,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default padding of each [`Tab`] of a [`Tabs`] widget.
+    pub const DEFAULT_PADDING: Padding = Padding::new(8);
+
+    /// Creates a new [`Tabs`] widget with the given [`State`], a list of
+    /// [`Tab`]s, the index of the active one, its content and a function that
+    /// produces a message when a [`Tab`] is selected.
+    pub fn new<F>(
+        state: &'a mut State,
+        tabs: Vec<Tab>,
+        active: usize,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        on_select: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        Tabs {
+            state,
+            tabs,
+            active,
+            content: content.into(),
+            on_select: Box::new(on_select),
+            on_close: None,
+            on_reorder: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 0,
+            text_size: None,
+            font: Default::default(),
+            icon_font: Default::default(),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the message that should be produced when the close button of a
+    /// [`Tab`] is pressed.
+    ///
+    /// Close buttons are only displayed when this is set.
+    pub fn on_close(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Tab`] is dragged to
+    /// a new position.
+    ///
+    /// The function receives the index of the dragged [`Tab`] and the index
+    /// it should be moved to, once removed from the list.
+    ///
+    /// Tabs can only be reordered when this is set.
+    pub fn on_reorder(
+        mut self,
+        f: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Padding`] of each [`Tab`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the horizontal spacing _between_ the [`Tab`]s.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the text size of the [`Tab`]s.
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the labels of the [`Tab`]s.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the font used to display the icons of the [`Tab`]s.
+    pub fn icon_font(mut self, font: Renderer::Font) -> Self {
+        self.icon_font = font;
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn is_closable(&self, tab: &Tab) -> bool {
+        self.on_close.is_some() && tab.is_closable
+    }
+
+    fn close_bounds(&self, bounds: Rectangle, text_size: f32) -> Rectangle {
+        Rectangle {
+            x: bounds.x + bounds.width
+                - f32::from(self.padding.right)
+                - text_size,
+            y: bounds.center_y() - text_size / 2.0,
+            width: text_size,
+            height: text_size,
+        }
+    }
+
+    fn draw_tab(
+        &self,
+        renderer: &mut Renderer,
+        index: usize,
+        bounds: Rectangle,
+        text_size: f32,
+        cursor_position: Point,
+    ) {
+        let tab = &self.tabs[index];
+        let is_selected = index == self.active;
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            self.style_sheet.hovered(is_selected)
+        } else {
+            self.style_sheet.active(is_selected)
+        };
+
+        if style.background.is_some() || style.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: style.border_radius,
+                    border_width: style.border_width,
+                    border_color: style.border_color,
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        let mut x = bounds.x + f32::from(self.padding.left);
+
+        if let Some(icon) = tab.icon {
+            renderer.fill_text(Text {
+                content: &icon.to_string(),
+                font: self.icon_font.clone(),
+                size: text_size,
+                bounds: Rectangle {
+                    x,
+                    y: bounds.center_y(),
+                    width: text_size,
+                    height: text_size,
+                },
+                color: style.icon_color,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+
+            x += text_size + ICON_SPACING;
+        }
+
+        let mut label_right =
+            bounds.x + bounds.width - f32::from(self.padding.right);
+
+        if self.is_closable(tab) {
+            label_right -= text_size + CLOSE_SPACING;
+        }
+
+        renderer.fill_text(Text {
+            content: &tab.label,
+            font: self.font.clone(),
+            size: text_size,
+            bounds: Rectangle {
+                x,
+                y: bounds.center_y(),
+                width: (label_right - x).max(0.0),
+                height: bounds.height,
+            },
+            color: style.text_color,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+
+        if self.is_closable(tab) {
+            let close_bounds = self.close_bounds(bounds, text_size);
+
+            renderer.fill_text(Text {
+                content: "×",
+                font: Default::default(),
+                size: text_size,
+                bounds: Rectangle {
+                    x: close_bounds.center_x(),
+                    y: close_bounds.center_y(),
+                    ..close_bounds
+                },
+                color: style.close_color,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tabs<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let bar_height =
+            f32::from(text_size) + f32::from(self.padding.vertical());
+
+        let mut x = 0.0;
+
+        let tabs = self
+            .tabs
+            .iter()
+            .map(|tab| {
+                let mut width = renderer.measure_width(
+                    &tab.label,
+                    text_size,
+                    self.font.clone(),
+                ) + f32::from(self.padding.horizontal());
+
+                if tab.icon.is_some() {
+                    width += f32::from(text_size) + ICON_SPACING;
+                }
+
+                if self.is_closable(tab) {
+                    width += f32::from(text_size) + CLOSE_SPACING;
+                }
+
+                let mut node =
+                    layout::Node::new(Size::new(width.ceil(), bar_height));
+
+                node.move_to(Point::new(x, 0.0));
+                x += node.size().width + f32::from(self.spacing);
+
+                node
+            })
+            .collect();
+
+        let mut content = self
+            .content
+            .layout(renderer, &limits.shrink(Size::new(0.0, bar_height)));
+
+        content.move_to(Point::new(0.0, bar_height));
+
+        let content_size = content.size();
+
+        let size = limits.resolve(Size::new(
+            content_size.width,
+            content_size.height + bar_height,
+        ));
+
+        let bar = layout::Node::with_children(
+            Size::new(size.width, bar_height),
+            tabs,
+        );
+
+        layout::Node::with_children(size, vec![bar, content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let bar_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let bar = Bar::new(bar_layout, self.state.offset);
+        let text_size =
+            f32::from(self.text_size.unwrap_or(renderer.default_size()));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some((left, right)) = bar.scroll_buttons {
+                    let step = bar.viewport.width / 2.0;
+
+                    if left.contains(cursor_position) {
+                        self.state.offset = (bar.offset - step).max(0.0);
+
+                        return event::Status::Captured;
+                    } else if right.contains(cursor_position) {
+                        self.state.offset =
+                            (bar.offset + step).min(bar.max_offset);
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if let Some((index, bounds)) = bar.tab_at(cursor_position) {
+                    if self.is_closable(&self.tabs[index])
+                        && self
+                            .close_bounds(bounds, text_size)
+                            .contains(cursor_position)
+                    {
+                        if let Some(on_close) = &self.on_close {
+                            shell.publish(on_close(index));
+                        }
+                    } else {
+                        if index != self.active {
+                            shell.publish((self.on_select)(index));
+                        }
+
+                        if self.on_reorder.is_some() {
+                            self.state.drag = Some(Drag {
+                                index,
+                                origin: cursor_position.x,
+                                position: cursor_position.x,
+                            });
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = &mut self.state.drag {
+                    drag.position = cursor_position.x;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Some(drag) = self.state.drag.take() {
+                    if drag.is_moving() {
+                        let target = bar
+                            .tabs()
+                            .filter(|(index, bounds)| {
+                                *index != drag.index
+                                    && bounds.center_x() < cursor_position.x
+                            })
+                            .count();
+
+                        if target != drag.index {
+                            if let Some(on_reorder) = &self.on_reorder {
+                                shell.publish(on_reorder(drag.index, target));
+                            }
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bar.scroll_buttons.is_some()
+                    && bar_layout.bounds().contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (if x != 0.0 { x } else { y }) * 60.0
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x != 0.0 {
+                            x
+                        } else {
+                            y
+                        }
+                    }
+                };
+
+                self.state.offset =
+                    (bar.offset - delta).max(0.0).min(bar.max_offset);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        self.content.on_event(
+            event,
+            content_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let bar_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        if self.state.is_dragging() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let bar = Bar::new(bar_layout, self.state.offset);
+
+        let is_over_scroll_button = match bar.scroll_buttons {
+            Some((left, right)) => {
+                left.contains(cursor_position)
+                    || right.contains(cursor_position)
+            }
+            None => false,
+        };
+
+        if is_over_scroll_button || bar.tab_at(cursor_position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            self.content.mouse_interaction(
+                content_layout,
+                cursor_position,
+                viewport,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let bar_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let bar = Bar::new(bar_layout, self.state.offset);
+        let bar_style = self.style_sheet.active(false);
+        let text_size =
+            f32::from(self.text_size.unwrap_or(renderer.default_size()));
+
+        if let Some(background) = bar_style.bar_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bar_layout.bounds(),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        let drag = self.state.drag.filter(Drag::is_moving);
+
+        renderer.with_layer(bar.viewport, |renderer| {
+            for (index, bounds) in bar.tabs() {
+                if matches!(drag, Some(drag) if drag.index == index) {
+                    continue;
+                }
+
+                self.draw_tab(
+                    renderer,
+                    index,
+                    bounds,
+                    text_size,
+                    cursor_position,
+                );
+            }
+
+            if let Some(drag) = drag {
+                if let Some((index, bounds)) = bar.tabs().nth(drag.index) {
+                    let bounds =
+                        bounds + Vector::new(drag.position - drag.origin, 0.0);
+
+                    self.draw_tab(
+                        renderer,
+                        index,
+                        bounds,
+                        text_size,
+                        cursor_position,
+                    );
+                }
+            }
+        });
+
+        if let Some((left, right)) = bar.scroll_buttons {
+            let color = bar_style.scroll_button_color;
+            let faded = Color {
+                a: color.a * 0.3,
+                ..color
+            };
+
+            draw_arrow(
+                renderer,
+                left,
+                true,
+                if bar.offset > 0.0 { color } else { faded },
+            );
+
+            draw_arrow(
+                renderer,
+                right,
+                false,
+                if bar.offset < bar.max_offset {
+                    color
+                } else {
+                    faded
+                },
+            );
+        }
+
+        self.content.draw(
+            renderer,
+            style,
+            content_layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.padding.hash(state);
+        self.spacing.hash(state);
+        self.text_size.hash(state);
+        self.on_close.is_some().hash(state);
+
+        for tab in &self.tabs {
+            tab.hash(state);
+        }
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().nth(1).unwrap())
+    }
+}
+
+/// A tab of a [`Tabs`] widget.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tab {
+    label: String,
+    icon: Option<char>,
+    is_closable: bool,
+}
+
+impl Tab {
+    /// Creates a new [`Tab`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Tab {
+            label: label.into(),
+            icon: None,
+            is_closable: true,
+        }
+    }
+
+    /// Sets the icon of the [`Tab`].
+    ///
+    /// The icon is displayed before the label, using the icon font of the
+    /// [`Tabs`] widget.
+    pub fn icon(mut self, icon: char) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets whether the [`Tab`] displays a close button or not.
+    ///
+    /// By default, every [`Tab`] can be closed if the [`Tabs`] widget
+    /// produces close messages.
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+}
+
+/// The local state of a [`Tabs`] widget.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    offset: f32,
+    drag: Option<Drag>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether a [`Tab`] is currently being dragged or not.
+    pub fn is_dragging(&self) -> bool {
+        matches!(self.drag, Some(drag) if drag.is_moving())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    position: f32,
+}
+
+impl Drag {
+    fn is_moving(&self) -> bool {
+        (self.position - self.origin).abs() > DRAG_THRESHOLD
+    }
+}
+
+/// The scrolled geometry of the tab bar.
+struct Bar<'a> {
+    layout: Layout<'a>,
+    viewport: Rectangle,
+    scroll_buttons: Option<(Rectangle, Rectangle)>,
+    offset: f32,
+    max_offset: f32,
+}
+
+impl<'a> Bar<'a> {
+    fn new(layout: Layout<'a>, offset: f32) -> Self {
+        let bounds = layout.bounds();
+
+        let tabs_width = layout
+            .children()
+            .last()
+            .map(|tab| {
+                let tab_bounds = tab.bounds();
+
+                tab_bounds.x + tab_bounds.width - bounds.x
+            })
+            .unwrap_or(0.0);
+
+        if tabs_width <= bounds.width {
+            return Bar {
+                layout,
+                viewport: bounds,
+                scroll_buttons: None,
+                offset: 0.0,
+                max_offset: 0.0,
+            };
+        }
+
+        let button_width = bounds.height.min(bounds.width / 4.0);
+
+        let viewport = Rectangle {
+            width: bounds.width - 2.0 * button_width,
+            ..bounds
+        };
+
+        let left = Rectangle {
+            x: viewport.x + viewport.width,
+            width: button_width,
+            ..bounds
+        };
+
+        let right = Rectangle {
+            x: left.x + button_width,
+            ..left
+        };
+
+        let max_offset = tabs_width - viewport.width;
+
+        Bar {
+            layout,
+            viewport,
+            scroll_buttons: Some((left, right)),
+            offset: offset.max(0.0).min(max_offset),
+            max_offset,
+        }
+    }
+
+    fn tabs(&self) -> impl Iterator<Item = (usize, Rectangle)> + 'a {
+        let translation = Vector::new(-self.offset, 0.0);
+
+        self.layout
+            .children()
+            .map(move |tab| tab.bounds() + translation)
+            .enumerate()
+    }
+
+    fn tab_at(&self, cursor_position: Point) -> Option<(usize, Rectangle)> {
+        if !self.viewport.contains(cursor_position) {
+            return None;
+        }
+
+        self.tabs()
+            .find(|(_, bounds)| bounds.contains(cursor_position))
+    }
+}
+
+const ICON_SPACING: f32 = 6.0;
+const CLOSE_SPACING: f32 = 8.0;
+const DRAG_THRESHOLD: f32 = 5.0;
+
+/// Draws a small triangle pointing left or right, centered in the given
+/// bounds.
+fn draw_arrow<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    points_left: bool,
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    const STEPS: usize = 4;
+    const STEP_WIDTH: f32 = 1.5;
+
+    let left = bounds.center_x() - STEPS as f32 * STEP_WIDTH / 2.0;
+    let center_y = bounds.center_y();
+
+    for step in 0..STEPS {
+        let height = if points_left {
+            2.0 * (step + 1) as f32
+        } else {
+            2.0 * (STEPS - step) as f32
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: left + step as f32 * STEP_WIDTH,
+                    y: center_y - height / 2.0,
+                    width: STEP_WIDTH,
+                    height,
+                },
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            color,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Tabs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+    Message: 'a,
+{
+    fn from(
+        tabs: Tabs<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tabs)
+    }
+}
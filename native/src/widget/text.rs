@@ -126,9 +126,16 @@ where
         let (width, height) =
             renderer.measure(&self.content, size, self.font.clone(), bounds);
 
+        let ascent = renderer.ascent(size, self.font.clone());
         let size = limits.resolve(Size::new(width, height));
 
-        layout::Node::new(size)
+        let offset = match self.vertical_alignment {
+            alignment::Vertical::Top => 0.0,
+            alignment::Vertical::Center => (size.height - height) / 2.0,
+            alignment::Vertical::Bottom => size.height - height,
+        };
+
+        layout::Node::new(size).with_baseline(offset + ascent)
     }
 
     fn draw(
@@ -20,8 +20,8 @@ use crate::renderer;
 use crate::text::{self, Text};
 use crate::touch;
 use crate::{
-    Clipboard, Color, Element, Hasher, Layout, Length, Padding, Point,
-    Rectangle, Shell, Size, Vector, Widget,
+    Clipboard, Color, Direction, Element, Hasher, Layout, Length, Padding,
+    Point, Rectangle, Shell, Size, Vector, Widget,
 };
 
 use std::u32;
@@ -30,6 +30,10 @@ pub use iced_style::text_input::{Style, StyleSheet};
 
 /// A field that can be filled with text.
 ///
+/// In a right-to-left layout, a value that fits in the [`TextInput`] is aligned
+/// to its right, and the `Left` and `Right` arrow keys are swapped, so `Left`
+/// moves the cursor towards the end of the value.
+///
 /// # Example
 /// ```
 /// # use iced_native::renderer::Null;
@@ -184,7 +188,8 @@ where
         let value = secure_value.as_ref().unwrap_or(&value);
 
         let bounds = layout.bounds();
-        let text_bounds = layout.children().next().unwrap().bounds();
+        let text_layout = layout.children().next().unwrap();
+        let text_bounds = text_layout.bounds();
 
         let is_mouse_over = bounds.contains(cursor_position);
 
@@ -209,10 +214,10 @@ where
         let text = value.to_string();
         let size = self.size.unwrap_or(renderer.default_size());
 
-        let (cursor, offset) = if self.state.is_focused() {
+        let cursor = if self.state.is_focused() {
             match self.state.cursor.state(&value) {
                 cursor::State::Index(position) => {
-                    let (text_value_width, offset) =
+                    let (text_value_width, _) =
                         measure_cursor_and_scroll_offset(
                             renderer,
                             text_bounds,
@@ -222,77 +227,86 @@ where
                             self.font.clone(),
                         );
 
-                    (
-                        Some((
-                            renderer::Quad {
-                                bounds: Rectangle {
-                                    x: text_bounds.x + text_value_width,
-                                    y: text_bounds.y,
-                                    width: 1.0,
-                                    height: text_bounds.height,
-                                },
-                                border_radius: 0.0,
-                                border_width: 0.0,
-                                border_color: Color::TRANSPARENT,
+                    Some((
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: text_bounds.x + text_value_width,
+                                y: text_bounds.y,
+                                width: 1.0,
+                                height: text_bounds.height,
                             },
-                            self.style_sheet.value_color(),
-                        )),
-                        offset,
-                    )
+                            border_radius: 0.0,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        self.style_sheet.value_color(),
+                    ))
                 }
                 cursor::State::Selection { start, end } => {
                     let left = start.min(end);
                     let right = end.max(start);
 
-                    let (left_position, left_offset) =
-                        measure_cursor_and_scroll_offset(
-                            renderer,
-                            text_bounds,
-                            &value,
-                            size,
-                            left,
-                            self.font.clone(),
-                        );
+                    let (left_position, _) = measure_cursor_and_scroll_offset(
+                        renderer,
+                        text_bounds,
+                        &value,
+                        size,
+                        left,
+                        self.font.clone(),
+                    );
 
-                    let (right_position, right_offset) =
-                        measure_cursor_and_scroll_offset(
-                            renderer,
-                            text_bounds,
-                            &value,
-                            size,
-                            right,
-                            self.font.clone(),
-                        );
+                    let (right_position, _) = measure_cursor_and_scroll_offset(
+                        renderer,
+                        text_bounds,
+                        &value,
+                        size,
+                        right,
+                        self.font.clone(),
+                    );
 
                     let width = right_position - left_position;
 
-                    (
-                        Some((
-                            renderer::Quad {
-                                bounds: Rectangle {
-                                    x: text_bounds.x + left_position,
-                                    y: text_bounds.y,
-                                    width,
-                                    height: text_bounds.height,
-                                },
-                                border_radius: 0.0,
-                                border_width: 0.0,
-                                border_color: Color::TRANSPARENT,
+                    Some((
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: text_bounds.x + left_position,
+                                y: text_bounds.y,
+                                width,
+                                height: text_bounds.height,
                             },
-                            self.style_sheet.selection_color(),
-                        )),
-                        if end == right {
-                            right_offset
-                        } else {
-                            left_offset
+                            border_radius: 0.0,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
                         },
-                    )
+                        self.style_sheet.selection_color(),
+                    ))
                 }
             }
         } else {
-            (None, 0.0)
+            None
         };
 
+        let translation = translation(
+            renderer,
+            text_layout,
+            self.font.clone(),
+            size,
+            value,
+            &self.state,
+        );
+
+        // In a right-to-left layout, the text ends where the value ends, so
+        // the placeholder of an empty value is aligned to the right as well.
+        let (text_x, horizontal_alignment) =
+            if text_layout.direction().is_right_to_left() {
+                let value_width =
+                    renderer.measure_width(&text, size, self.font.clone());
+
+                (text_bounds.x + value_width, alignment::Horizontal::Right)
+            } else {
+                (text_bounds.x, alignment::Horizontal::Left)
+            };
+
         let text_width = renderer.measure_width(
             if text.is_empty() {
                 &self.placeholder
@@ -321,22 +335,23 @@ where
                 },
                 font: self.font.clone(),
                 bounds: Rectangle {
+                    x: text_x,
                     y: text_bounds.center_y(),
                     width: f32::INFINITY,
                     ..text_bounds
                 },
                 size: f32::from(size),
-                horizontal_alignment: alignment::Horizontal::Left,
+                horizontal_alignment,
                 vertical_alignment: alignment::Vertical::Center,
             });
         };
 
         if text_width > text_bounds.width {
             renderer.with_layer(text_bounds, |renderer| {
-                renderer.with_translation(Vector::new(-offset, 0.0), render)
+                renderer.with_translation(Vector::new(translation, 0.0), render)
             });
         } else {
-            render(renderer);
+            renderer.with_translation(Vector::new(translation, 0.0), render);
         }
     }
 }
@@ -368,13 +383,23 @@ where
             .max_width(self.max_width)
             .height(Length::Units(text_size));
 
-        let mut text = layout::Node::new(limits.resolve(Size::ZERO));
+        let mut text = layout::Node::new(limits.resolve(Size::ZERO))
+            .with_direction(limits.direction());
         text.move_to(Point::new(
             self.padding.left.into(),
             self.padding.top.into(),
         ));
 
+        // The value is centered vertically, so its baseline sits below the
+        // middle of the text bounds by the ascent minus half a line.
+        let (_, line_height) =
+            renderer.measure(" ", text_size, self.font.clone(), Size::INFINITY);
+        let baseline = f32::from(self.padding.top)
+            + (f32::from(text_size) - line_height) / 2.0
+            + renderer.ascent(text_size, self.font.clone());
+
         layout::Node::with_children(text.size().pad(self.padding), vec![text])
+            .with_baseline(baseline)
     }
 
     fn on_event(
@@ -413,7 +438,7 @@ where
 
                                 find_cursor_position(
                                     renderer,
-                                    text_layout.bounds(),
+                                    text_layout,
                                     self.font.clone(),
                                     self.size,
                                     &value,
@@ -433,7 +458,7 @@ where
                             } else {
                                 let position = find_cursor_position(
                                     renderer,
-                                    text_layout.bounds(),
+                                    text_layout,
                                     self.font.clone(),
                                     self.size,
                                     &self.value,
@@ -480,7 +505,7 @@ where
 
                     let position = find_cursor_position(
                         renderer,
-                        text_layout.bounds(),
+                        text_layout,
                         self.font.clone(),
                         self.size,
                         &value,
@@ -518,6 +543,21 @@ where
             }) if self.state.is_focused => {
                 let modifiers = self.state.keyboard_modifiers;
 
+                // In a right-to-left layout, the value flows from right to
+                // left and the arrow keys move the cursor accordingly.
+                let key_code = match (
+                    key_code,
+                    layout.children().next().unwrap().direction(),
+                ) {
+                    (keyboard::KeyCode::Left, Direction::RightToLeft) => {
+                        keyboard::KeyCode::Right
+                    }
+                    (keyboard::KeyCode::Right, Direction::RightToLeft) => {
+                        keyboard::KeyCode::Left
+                    }
+                    _ => key_code,
+                };
+
                 match key_code {
                     keyboard::KeyCode::Enter
                     | keyboard::KeyCode::NumpadEnter => {
@@ -925,6 +965,36 @@ where
     }
 }
 
+/// Returns the horizontal translation of the value of a [`TextInput`] inside
+/// its text bounds.
+///
+/// A value wider than the bounds is scrolled to keep the cursor visible, and
+/// a value that fits is aligned to the right in a right-to-left layout.
+fn translation<Renderer>(
+    renderer: &Renderer,
+    text_layout: Layout<'_>,
+    font: Renderer::Font,
+    size: u16,
+    value: &Value,
+    state: &State,
+) -> f32
+where
+    Renderer: text::Renderer,
+{
+    let text_bounds = text_layout.bounds();
+
+    let value_width =
+        renderer.measure_width(&value.to_string(), size, font.clone());
+
+    if value_width > text_bounds.width {
+        -offset(renderer, text_bounds, font, size, value, state)
+    } else if text_layout.direction().is_right_to_left() {
+        text_bounds.width - value_width
+    } else {
+        0.0
+    }
+}
+
 fn measure_cursor_and_scroll_offset<Renderer>(
     renderer: &Renderer,
     text_bounds: Rectangle,
@@ -950,7 +1020,7 @@ where
 /// a [`TextInput`].
 fn find_cursor_position<Renderer>(
     renderer: &Renderer,
-    text_bounds: Rectangle,
+    text_layout: Layout<'_>,
     font: Renderer::Font,
     size: Option<u16>,
     value: &Value,
@@ -962,8 +1032,10 @@ where
 {
     let size = size.unwrap_or(renderer.default_size());
 
-    let offset =
-        offset(renderer, text_bounds, font.clone(), size, &value, &state);
+    let text_bounds = text_layout.bounds();
+
+    let translation =
+        translation(renderer, text_layout, font.clone(), size, value, state);
 
     renderer
         .hit_test(
@@ -971,7 +1043,7 @@ where
             size.into(),
             font.clone(),
             Size::INFINITY,
-            Point::new(x + offset, text_bounds.height / 2.0),
+            Point::new(x - translation, text_bounds.height / 2.0),
             true,
         )
         .map(text::Hit::cursor)
@@ -1,5 +1,6 @@
 //! Display a widget over another.
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
 use iced_core::Rectangle;
 
@@ -10,20 +11,32 @@ use crate::renderer;
 use crate::text;
 use crate::widget::container;
 use crate::widget::text::Text;
+use crate::window;
 use crate::{
-    Clipboard, Element, Event, Hasher, Layout, Length, Padding, Point, Shell,
-    Size, Vector, Widget,
+    Background, Clipboard, Color, Element, Event, Hasher, Layout, Length,
+    Padding, Point, Shell, Size, Vector, Widget,
 };
 
 /// An element to display a widget over another.
+///
+/// The tooltip is flipped to the opposite side of its content when it does
+/// not fit in the viewport, and it is always kept inside of it.
 #[allow(missing_debug_implementations)]
 pub struct Tooltip<'a, Message, Renderer: text::Renderer> {
     content: Element<'a, Message, Renderer>,
-    tooltip: Text<Renderer>,
+    tooltip: Content<'a, Message, Renderer>,
     position: Position,
     style_sheet: Box<dyn container::StyleSheet + 'a>,
     gap: u16,
     padding: u16,
+    arrow: u16,
+    delay: Option<(&'a mut State, Duration)>,
+}
+
+/// The contents of the tooltip of a [`Tooltip`].
+enum Content<'a, Message, Renderer: text::Renderer> {
+    Text(Text<Renderer>),
+    Element(Element<'a, Message, Renderer>),
 }
 
 impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
@@ -40,28 +53,66 @@ where
         content: impl Into<Element<'a, Message, Renderer>>,
         tooltip: impl ToString,
         position: Position,
+    ) -> Self {
+        Self::with_content(
+            content,
+            Content::Text(Text::new(tooltip.to_string())),
+            position,
+        )
+    }
+
+    /// Creates a new [`Tooltip`] showing the given [`Element`] over its
+    /// content.
+    ///
+    /// The [`Element`] is only displayed; it does not receive any events.
+    pub fn with_element(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        tooltip: impl Into<Element<'a, Message, Renderer>>,
+        position: Position,
+    ) -> Self {
+        Self::with_content(content, Content::Element(tooltip.into()), position)
+    }
+
+    fn with_content(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        tooltip: Content<'a, Message, Renderer>,
+        position: Position,
     ) -> Self {
         Tooltip {
             content: content.into(),
-            tooltip: Text::new(tooltip.to_string()),
+            tooltip,
             position,
             style_sheet: Default::default(),
             gap: 0,
             padding: Self::DEFAULT_PADDING,
+            arrow: 0,
+            delay: None,
         }
     }
 
     /// Sets the size of the text of the [`Tooltip`].
+    ///
+    /// It has no effect on a [`Tooltip`] created with
+    /// [`Tooltip::with_element`].
     pub fn size(mut self, size: u16) -> Self {
-        self.tooltip = self.tooltip.size(size);
+        if let Content::Text(text) = self.tooltip {
+            self.tooltip = Content::Text(text.size(size));
+        }
+
         self
     }
 
     /// Sets the font of the [`Tooltip`].
     ///
+    /// It has no effect on a [`Tooltip`] created with
+    /// [`Tooltip::with_element`].
+    ///
     /// [`Font`]: Renderer::Font
     pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
-        self.tooltip = self.tooltip.font(font);
+        if let Content::Text(text) = self.tooltip {
+            self.tooltip = Content::Text(text.font(font));
+        }
+
         self
     }
 
@@ -77,6 +128,26 @@ where
         self
     }
 
+    /// Sets the size of the arrow pointing from the [`Tooltip`] to its
+    /// content.
+    ///
+    /// The arrow is drawn with the background of the [`Tooltip`], between the
+    /// [`Tooltip`] and the gap. A size of `0` draws no arrow, which is the
+    /// default.
+    pub fn arrow(mut self, size: u16) -> Self {
+        self.arrow = size;
+        self
+    }
+
+    /// Sets the time the content of the [`Tooltip`] needs to be hovered
+    /// before the [`Tooltip`] is shown.
+    ///
+    /// The [`State`] keeps track of when the content started being hovered.
+    pub fn delay(mut self, state: &'a mut State, delay: Duration) -> Self {
+        self.delay = Some((state, delay));
+        self
+    }
+
     /// Sets the style of the [`Tooltip`].
     pub fn style(
         mut self,
@@ -85,6 +156,39 @@ where
         self.style_sheet = style_sheet.into();
         self
     }
+
+    fn tooltip(&self) -> &dyn Widget<Message, Renderer> {
+        match &self.tooltip {
+            Content::Text(text) => text,
+            Content::Element(element) => element.widget.as_ref(),
+        }
+    }
+
+    /// Returns whether the tooltip should be displayed for the given cursor
+    /// position.
+    fn is_shown(&self, bounds: Rectangle, cursor_position: Point) -> bool {
+        bounds.contains(cursor_position)
+            && match &self.delay {
+                Some((state, delay)) => matches!(
+                    state.hovered_since,
+                    Some(since) if since + *delay <= Instant::now()
+                ),
+                None => true,
+            }
+    }
+}
+
+/// The local state of a [`Tooltip`] with a delay.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    hovered_since: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
 /// The position of the tooltip. Defaults to following the cursor.
@@ -102,6 +206,42 @@ pub enum Position {
     Right,
 }
 
+impl Position {
+    fn opposite(self) -> Self {
+        match self {
+            Position::FollowCursor => Position::FollowCursor,
+            Position::Top => Position::Bottom,
+            Position::Bottom => Position::Top,
+            Position::Left => Position::Right,
+            Position::Right => Position::Left,
+        }
+    }
+
+    /// Returns whether a tooltip of the given size fits in the viewport when
+    /// placed in this [`Position`] around the given bounds.
+    fn fits(
+        self,
+        bounds: Rectangle,
+        size: Size,
+        gap: f32,
+        viewport: &Rectangle,
+    ) -> bool {
+        match self {
+            Position::FollowCursor => true,
+            Position::Top => bounds.y - gap - size.height >= viewport.y,
+            Position::Bottom => {
+                bounds.y + bounds.height + gap + size.height
+                    <= viewport.y + viewport.height
+            }
+            Position::Left => bounds.x - gap - size.width >= viewport.x,
+            Position::Right => {
+                bounds.x + bounds.width + gap + size.width
+                    <= viewport.x + viewport.width
+            }
+        }
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Tooltip<'a, Message, Renderer>
 where
@@ -132,6 +272,29 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        if let Some((state, delay)) = &mut self.delay {
+            let is_hovered = layout.bounds().contains(cursor_position);
+
+            match (state.hovered_since, &event) {
+                (None, _) if is_hovered => {
+                    let now = Instant::now();
+
+                    state.hovered_since = Some(now);
+                    shell.request_redraw(now + *delay);
+                }
+                (Some(_), _) if !is_hovered => {
+                    state.hovered_since = None;
+                }
+                (
+                    Some(since),
+                    Event::Window(window::Event::RedrawRequested(now)),
+                ) if *now < since + *delay => {
+                    shell.request_redraw(since + *delay);
+                }
+                _ => {}
+            }
+        }
+
         self.content.widget.on_event(
             event,
             layout,
@@ -170,8 +333,9 @@ where
 
         let bounds = layout.bounds();
 
-        if bounds.contains(cursor_position) {
+        if self.is_shown(bounds, cursor_position) {
             let gap = f32::from(self.gap);
+            let arrow = f32::from(self.arrow);
             let style = self.style_sheet.style();
 
             let defaults = renderer::Style {
@@ -180,49 +344,62 @@ where
                     .unwrap_or(inherited_style.text_color),
             };
 
-            let text_layout = Widget::<(), Renderer>::layout(
-                &self.tooltip,
+            let tooltip = self.tooltip();
+
+            let tooltip_layout = tooltip.layout(
                 renderer,
                 &layout::Limits::new(Size::ZERO, viewport.size())
                     .pad(Padding::new(self.padding)),
             );
 
             let padding = f32::from(self.padding);
-            let text_bounds = text_layout.bounds();
-            let x_center = bounds.x + (bounds.width - text_bounds.width) / 2.0;
-            let y_center =
-                bounds.y + (bounds.height - text_bounds.height) / 2.0;
-
-            let mut tooltip_bounds = {
-                let offset = match self.position {
-                    Position::Top => Vector::new(
-                        x_center,
-                        bounds.y - text_bounds.height - gap - padding,
-                    ),
-                    Position::Bottom => Vector::new(
-                        x_center,
-                        bounds.y + bounds.height + gap + padding,
-                    ),
-                    Position::Left => Vector::new(
-                        bounds.x - text_bounds.width - gap - padding,
-                        y_center,
-                    ),
-                    Position::Right => Vector::new(
-                        bounds.x + bounds.width + gap + padding,
-                        y_center,
-                    ),
-                    Position::FollowCursor => Vector::new(
-                        cursor_position.x,
-                        cursor_position.y - text_bounds.height,
-                    ),
+            let content_bounds = tooltip_layout.bounds();
+            let size = Size::new(
+                content_bounds.width + padding * 2.0,
+                content_bounds.height + padding * 2.0,
+            );
+
+            let position =
+                if !self.position.fits(bounds, size, gap + arrow, viewport)
+                    && self.position.opposite().fits(
+                        bounds,
+                        size,
+                        gap + arrow,
+                        viewport,
+                    )
+                {
+                    self.position.opposite()
+                } else {
+                    self.position
                 };
 
-                Rectangle {
-                    x: offset.x - padding,
-                    y: offset.y - padding,
-                    width: text_bounds.width + padding * 2.0,
-                    height: text_bounds.height + padding * 2.0,
+            let x_center = bounds.center_x() - size.width / 2.0;
+            let y_center = bounds.center_y() - size.height / 2.0;
+
+            let (x, y) = match position {
+                Position::Top => {
+                    (x_center, bounds.y - size.height - gap - arrow)
                 }
+                Position::Bottom => {
+                    (x_center, bounds.y + bounds.height + gap + arrow)
+                }
+                Position::Left => {
+                    (bounds.x - size.width - gap - arrow, y_center)
+                }
+                Position::Right => {
+                    (bounds.x + bounds.width + gap + arrow, y_center)
+                }
+                Position::FollowCursor => (
+                    cursor_position.x - padding,
+                    cursor_position.y - content_bounds.height - padding,
+                ),
+            };
+
+            let mut tooltip_bounds = Rectangle {
+                x,
+                y,
+                width: size.width,
+                height: size.height,
             };
 
             if tooltip_bounds.x < viewport.x {
@@ -246,8 +423,18 @@ where
             renderer.with_layer(*viewport, |renderer| {
                 container::draw_background(renderer, &style, tooltip_bounds);
 
-                Widget::<(), Renderer>::draw(
-                    &self.tooltip,
+                if let Some(background) = style.background {
+                    draw_arrow(
+                        renderer,
+                        position,
+                        self.arrow,
+                        bounds,
+                        tooltip_bounds,
+                        background,
+                    );
+                }
+
+                tooltip.draw(
                     renderer,
                     &defaults,
                     Layout::with_offset(
@@ -255,7 +442,7 @@ where
                             tooltip_bounds.x + padding,
                             tooltip_bounds.y + padding,
                         ),
-                        &text_layout,
+                        &tooltip_layout,
                     ),
                     cursor_position,
                     viewport,
@@ -272,6 +459,91 @@ where
     }
 }
 
+/// Draws an arrow of the given size pointing from the bounds of a tooltip to
+/// the bounds of its content.
+///
+/// The arrow is drawn as a stack of lines, each one narrower than the last.
+fn draw_arrow<Renderer>(
+    renderer: &mut Renderer,
+    position: Position,
+    size: u16,
+    content_bounds: Rectangle,
+    tooltip_bounds: Rectangle,
+    background: Background,
+) where
+    Renderer: crate::Renderer,
+{
+    let size = f32::from(size);
+
+    let is_vertical = matches!(position, Position::Top | Position::Bottom);
+    let edge_length = if is_vertical {
+        tooltip_bounds.width
+    } else {
+        tooltip_bounds.height
+    };
+
+    if size == 0.0
+        || position == Position::FollowCursor
+        || edge_length < size * 2.0
+    {
+        return;
+    }
+
+    let center = if is_vertical {
+        content_bounds.center_x().clamp(
+            tooltip_bounds.x + size,
+            tooltip_bounds.x + tooltip_bounds.width - size,
+        )
+    } else {
+        content_bounds.center_y().clamp(
+            tooltip_bounds.y + size,
+            tooltip_bounds.y + tooltip_bounds.height - size,
+        )
+    };
+
+    for i in 0..size as u16 {
+        let offset = f32::from(i);
+        let half_length = size - offset;
+
+        let bounds = match position {
+            Position::Top => Rectangle {
+                x: center - half_length,
+                y: tooltip_bounds.y + tooltip_bounds.height + offset,
+                width: half_length * 2.0,
+                height: 1.0,
+            },
+            Position::Bottom => Rectangle {
+                x: center - half_length,
+                y: tooltip_bounds.y - offset - 1.0,
+                width: half_length * 2.0,
+                height: 1.0,
+            },
+            Position::Left => Rectangle {
+                x: tooltip_bounds.x + tooltip_bounds.width + offset,
+                y: center - half_length,
+                width: 1.0,
+                height: half_length * 2.0,
+            },
+            Position::Right | Position::FollowCursor => Rectangle {
+                x: tooltip_bounds.x - offset - 1.0,
+                y: center - half_length,
+                width: 1.0,
+                height: half_length * 2.0,
+            },
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            background,
+        );
+    }
+}
+
 impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
@@ -0,0 +1,923 @@
+//! Display hierarchical data in an expandable tree.
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::scrollable;
+use crate::{
+    Background, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
+    Point, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::hash::Hash;
+
+pub use iced_style::tree_view::{Style, StyleSheet};
+
+/// A widget displaying a hierarchy of [`Node`]s that can be expanded and
+/// collapsed.
+///
+/// A [`TreeView`] does not own the expansion nor the selection of its nodes.
+/// Instead, it produces messages when the user interacts with it, which allows
+/// the children of a [`Node`] to be loaded lazily when it is first expanded.
+///
+/// Once focused, a [`TreeView`] can be navigated with the arrow keys: `Up` and
+/// `Down` move the selection, `Left` collapses the selected [`Node`] (or
+/// selects its parent) and `Right` expands it (or selects its first child).
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{tree_view, Text};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type TreeView<'a, T, Message> =
+/// #     iced_native::widget::TreeView<'a, T, Message, Null>;
+/// # type Node<'a, T, Message> = tree_view::Node<'a, T, Message, Null>;
+/// #
+/// # let mut state = tree_view::State::new();
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Selected(u32),
+///     Toggled(u32, bool),
+/// }
+///
+/// let tree: TreeView<u32, Message> = TreeView::new(
+///     &mut state,
+///     vec![Node::new(0, Text::new("src"))
+///         .expanded(true)
+///         .push(Node::new(1, Text::new("main.rs")))
+///         .push(Node::new(2, Text::new("widget")).expandable(true))],
+/// )
+/// .selected(Some(1))
+/// .on_select(Message::Selected)
+/// .on_toggle(Message::Toggled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, T, Message, Renderer> {
+    state: &'a mut State,
+    rows: Vec<Row<'a, T, Message, Renderer>>,
+    selected: Option<T>,
+    on_select: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_toggle: Option<Box<dyn Fn(T, bool) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    indent: u16,
+    padding: Padding,
+    scrollbar_width: u16,
+    scrollbar_margin: u16,
+    scroller_width: u16,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+    scrollbar_style_sheet: Box<dyn scrollable::StyleSheet + 'a>,
+}
+
+impl<'a, T, Message, Renderer> TreeView<'a, T, Message, Renderer>
+where
+    T: Clone + PartialEq,
+    Renderer: crate::Renderer,
+{
+    /// The default indentation of each level of a [`TreeView`].
+    pub const DEFAULT_INDENT: u16 = 20;
+
+    /// The default padding of the rows of a [`TreeView`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(3);
+
+    /// Creates a new [`TreeView`] with the given [`State`] and root nodes.
+    pub fn new(
+        state: &'a mut State,
+        roots: Vec<Node<'a, T, Message, Renderer>>,
+    ) -> Self {
+        let mut rows = Vec::new();
+
+        flatten(roots, 0, None, &mut rows);
+
+        TreeView {
+            state,
+            rows,
+            selected: None,
+            on_select: None,
+            on_toggle: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            indent: Self::DEFAULT_INDENT,
+            padding: Self::DEFAULT_PADDING,
+            scrollbar_width: 10,
+            scrollbar_margin: 0,
+            scroller_width: 10,
+            style_sheet: Default::default(),
+            scrollbar_style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the identifier of the selected [`Node`] of the [`TreeView`].
+    pub fn selected(mut self, selected: Option<T>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is selected.
+    pub fn on_select(mut self, f: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is expanded
+    /// or collapsed.
+    ///
+    /// The function receives the identifier of the [`Node`] and whether it
+    /// should be expanded or not.
+    pub fn on_toggle(mut self, f: impl Fn(T, bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TreeView`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the indentation of each level of the [`TreeView`], in pixels.
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the [`Padding`] of the rows of the [`TreeView`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the scrollbar width of the [`TreeView`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn scrollbar_width(mut self, scrollbar_width: u16) -> Self {
+        self.scrollbar_width = scrollbar_width.max(1);
+        self
+    }
+
+    /// Sets the scrollbar margin of the [`TreeView`].
+    pub fn scrollbar_margin(mut self, scrollbar_margin: u16) -> Self {
+        self.scrollbar_margin = scrollbar_margin;
+        self
+    }
+
+    /// Sets the scroller width of the [`TreeView`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn scroller_width(mut self, scroller_width: u16) -> Self {
+        self.scroller_width = scroller_width.max(1);
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Sets the style of the scrollbar of the [`TreeView`].
+    pub fn scrollbar_style(
+        mut self,
+        style_sheet: impl Into<Box<dyn scrollable::StyleSheet + 'a>>,
+    ) -> Self {
+        self.scrollbar_style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Returns the scrollbar of the [`TreeView`].
+    fn scrollbar(&self) -> scrollable::Properties<'_, Message> {
+        scrollable::Properties {
+            scrollbar_width: self.scrollbar_width,
+            scrollbar_margin: self.scrollbar_margin,
+            scroller_width: self.scroller_width,
+            on_scroll: None,
+            style_sheet: self.scrollbar_style_sheet.as_ref(),
+        }
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+
+        self.rows.iter().position(|row| &row.id == selected)
+    }
+
+    fn toggle_bounds(
+        &self,
+        row: &Row<'_, T, Message, Renderer>,
+        bounds: Rectangle,
+    ) -> Rectangle {
+        let indent = f32::from(self.indent);
+
+        Rectangle {
+            x: bounds.x + row.depth as f32 * indent,
+            width: indent,
+            ..bounds
+        }
+    }
+
+    /// Processes a key press, returning the index of the row that should be
+    /// selected or toggled, if any.
+    fn navigate(
+        &self,
+        key_code: keyboard::KeyCode,
+        shell: &mut Shell<'_, Message>,
+    ) -> Option<usize> {
+        let last = self.rows.len().checked_sub(1)?;
+        let current = self.selected_index();
+
+        let target = match (key_code, current) {
+            (keyboard::KeyCode::Up, Some(current)) => current.saturating_sub(1),
+            (keyboard::KeyCode::Down, Some(current)) => (current + 1).min(last),
+            (keyboard::KeyCode::Up, None) | (keyboard::KeyCode::Home, _) => 0,
+            (keyboard::KeyCode::Down, None) | (keyboard::KeyCode::End, _) => {
+                last
+            }
+            (keyboard::KeyCode::Left, Some(current)) => {
+                let row = &self.rows[current];
+
+                if row.is_expanded {
+                    if let Some(on_toggle) = &self.on_toggle {
+                        shell.publish(on_toggle(row.id.clone(), false));
+                    }
+
+                    return Some(current);
+                }
+
+                row.parent?
+            }
+            (keyboard::KeyCode::Right, Some(current)) => {
+                let row = &self.rows[current];
+
+                if row.is_expandable && !row.is_expanded {
+                    if let Some(on_toggle) = &self.on_toggle {
+                        shell.publish(on_toggle(row.id.clone(), true));
+                    }
+
+                    return Some(current);
+                }
+
+                match self.rows.get(current + 1) {
+                    Some(child) if child.parent == Some(current) => current + 1,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        if Some(target) != current {
+            if let Some(on_select) = &self.on_select {
+                shell.publish(on_select(self.rows[target].id.clone()));
+            }
+        }
+
+        Some(target)
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for TreeView<'a, T, Message, Renderer>
+where
+    T: Clone + PartialEq,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let indent = f32::from(self.indent);
+        let max_width = limits.max().width;
+
+        let mut y = 0.0;
+        let mut width: f32 = 0.0;
+
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                let offset = (row.depth + 1) as f32 * indent;
+
+                let content_limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new((max_width - offset).max(0.0), f32::INFINITY),
+                )
+                .with_direction(limits.direction())
+                .pad(self.padding);
+
+                let content = row
+                    .content
+                    .layout(renderer, &content_limits)
+                    .translate(Vector::new(
+                        offset + f32::from(self.padding.left),
+                        f32::from(self.padding.top),
+                    ));
+
+                let size = content.size();
+                let height = (size.height + f32::from(self.padding.vertical()))
+                    .max(indent);
+
+                width = width.max(
+                    offset + size.width + f32::from(self.padding.horizontal()),
+                );
+
+                let mut node = layout::Node::with_children(
+                    Size::new(0.0, height),
+                    vec![content],
+                );
+
+                node.move_to(Point::new(0.0, y));
+                y += height;
+
+                node
+            })
+            .collect::<Vec<_>>();
+
+        let size = limits.resolve(Size::new(width, y));
+        let row_width = size.width.max(width);
+
+        let rows = rows
+            .into_iter()
+            .map(|node| {
+                let bounds = node.bounds();
+                let content = node.children()[0].clone();
+
+                let mut node = layout::Node::with_children(
+                    Size::new(row_width, bounds.height),
+                    vec![content],
+                );
+
+                node.move_to(Point::new(bounds.x, bounds.y));
+
+                node
+            })
+            .collect();
+
+        let content =
+            layout::Node::with_children(Size::new(row_width, y), rows);
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            self.state.is_focused = bounds.contains(cursor_position);
+        }
+
+        let mut pressed_row = None;
+
+        let status = {
+            let rows = &mut self.rows;
+
+            let scrollbar = scrollable::Properties {
+                scrollbar_width: self.scrollbar_width,
+                scrollbar_margin: self.scrollbar_margin,
+                scroller_width: self.scroller_width,
+                on_scroll: None,
+                style_sheet: self.scrollbar_style_sheet.as_ref(),
+            };
+
+            scrollable::update(
+                &mut self.state.scrollable,
+                &scrollbar,
+                event.clone(),
+                layout,
+                cursor_position,
+                shell,
+                |event, layout, cursor_position, shell| {
+                    let status = rows
+                        .iter_mut()
+                        .zip(layout.children())
+                        .map(|(row, layout)| {
+                            row.content.widget.on_event(
+                                event.clone(),
+                                layout.children().next().unwrap(),
+                                cursor_position,
+                                renderer,
+                                clipboard,
+                                shell,
+                            )
+                        })
+                        .fold(event::Status::Ignored, event::Status::merge);
+
+                    if status == event::Status::Ignored {
+                        if let Event::Mouse(mouse::Event::ButtonPressed(
+                            mouse::Button::Left,
+                        ))
+                        | Event::Touch(touch::Event::FingerPressed {
+                            ..
+                        }) = event
+                        {
+                            pressed_row = layout
+                                .children()
+                                .enumerate()
+                                .find(|(_, row)| {
+                                    row.bounds().contains(cursor_position)
+                                })
+                                .map(|(i, row)| {
+                                    (i, row.bounds(), cursor_position)
+                                });
+                        }
+                    }
+
+                    status
+                },
+            )
+        };
+
+        if status == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        if let Some((index, row_bounds, cursor_position)) = pressed_row {
+            let row = &self.rows[index];
+
+            if row.is_expandable
+                && self
+                    .toggle_bounds(row, row_bounds)
+                    .contains(cursor_position)
+            {
+                if let Some(on_toggle) = &self.on_toggle {
+                    shell.publish(on_toggle(row.id.clone(), !row.is_expanded));
+                }
+            } else if let Some(on_select) = &self.on_select {
+                shell.publish(on_select(row.id.clone()));
+            }
+
+            return event::Status::Captured;
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code, ..
+        }) = event
+        {
+            if self.state.is_focused {
+                if let Some(target) = self.navigate(key_code, shell) {
+                    let content = layout.children().next().unwrap();
+                    let content_bounds = content.bounds();
+
+                    if let Some(row) = content.children().nth(target) {
+                        let row_bounds = row.bounds();
+                        let offset = self
+                            .state
+                            .scrollable
+                            .offset(bounds, content_bounds)
+                            as f32;
+
+                        let top = row_bounds.y - content_bounds.y;
+                        let bottom = top + row_bounds.height;
+
+                        if top < offset {
+                            self.state.scrollable.scroll(
+                                offset - top,
+                                bounds,
+                                content_bounds,
+                            );
+                        } else if bottom > offset + bounds.height {
+                            self.state.scrollable.scroll(
+                                offset + bounds.height - bottom,
+                                bounds,
+                                content_bounds,
+                            );
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        scrollable::mouse_interaction(
+            &self.state.scrollable,
+            &self.scrollbar(),
+            layout,
+            cursor_position,
+            |layout, cursor_position, viewport| {
+                self.rows
+                    .iter()
+                    .zip(layout.children())
+                    .map(|(row, layout)| {
+                        let interaction = row.content.widget.mouse_interaction(
+                            layout.children().next().unwrap(),
+                            cursor_position,
+                            viewport,
+                        );
+
+                        let is_over_toggle = row.is_expandable
+                            && self
+                                .toggle_bounds(row, layout.bounds())
+                                .contains(cursor_position);
+
+                        if is_over_toggle {
+                            mouse::Interaction::Pointer
+                        } else {
+                            interaction
+                        }
+                    })
+                    .max()
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let tree_style = if self.state.is_focused {
+            self.style_sheet.focused()
+        } else {
+            self.style_sheet.active()
+        };
+
+        if tree_style.background.is_some() || tree_style.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: tree_style.border_radius,
+                    border_width: tree_style.border_width,
+                    border_color: tree_style.border_color,
+                },
+                tree_style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        let selected = self.selected_index();
+        let indent = f32::from(self.indent);
+
+        scrollable::draw(
+            &self.state.scrollable,
+            &self.scrollbar(),
+            renderer,
+            layout,
+            cursor_position,
+            |renderer, layout, cursor_position, viewport| {
+                for (i, (row, layout)) in
+                    self.rows.iter().zip(layout.children()).enumerate()
+                {
+                    let row_bounds = layout.bounds();
+
+                    if row_bounds.intersection(viewport).is_none() {
+                        continue;
+                    }
+
+                    let is_selected = selected == Some(i);
+
+                    let background = if is_selected {
+                        Some(tree_style.selected_background)
+                    } else if row_bounds.contains(cursor_position) {
+                        tree_style.hovered_background
+                    } else {
+                        None
+                    };
+
+                    if let Some(background) = background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: row_bounds,
+                                border_radius: 0.0,
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            background,
+                        );
+                    }
+
+                    if tree_style.guide_width > 0.0 {
+                        for level in 0..row.depth {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        x: row_bounds.x
+                                            + level as f32 * indent
+                                            + (indent - tree_style.guide_width)
+                                                / 2.0,
+                                        width: tree_style.guide_width,
+                                        ..row_bounds
+                                    },
+                                    border_radius: 0.0,
+                                    border_width: 0.0,
+                                    border_color: Color::TRANSPARENT,
+                                },
+                                tree_style.guide_color,
+                            );
+                        }
+                    }
+
+                    if row.is_expandable {
+                        draw_toggle(
+                            renderer,
+                            self.toggle_bounds(row, row_bounds),
+                            row.is_expanded,
+                            tree_style.toggle_color,
+                        );
+                    }
+
+                    let style = renderer::Style {
+                        text_color: match tree_style.selected_text_color {
+                            Some(color) if is_selected => color,
+                            _ => style.text_color,
+                        },
+                    };
+
+                    row.content.draw(
+                        renderer,
+                        &style,
+                        layout.children().next().unwrap(),
+                        cursor_position,
+                        viewport,
+                    );
+                }
+            },
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.indent.hash(state);
+        self.padding.hash(state);
+
+        for row in &self.rows {
+            row.depth.hash(state);
+            row.content.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let offset = self
+            .state
+            .scrollable
+            .offset(bounds, content_layout.bounds());
+
+        self.rows
+            .iter_mut()
+            .zip(content_layout.children())
+            .filter_map(|(row, layout)| {
+                row.content.overlay(layout.children().next().unwrap())
+            })
+            .next()
+            .map(|overlay| {
+                overlay.translate(Vector::new(0.0, -(offset as f32)))
+            })
+    }
+}
+
+/// A node of a [`TreeView`].
+#[allow(missing_debug_implementations)]
+pub struct Node<'a, T, Message, Renderer> {
+    id: T,
+    content: Element<'a, Message, Renderer>,
+    children: Vec<Node<'a, T, Message, Renderer>>,
+    is_expanded: bool,
+    is_expandable: bool,
+}
+
+impl<'a, T, Message, Renderer> Node<'a, T, Message, Renderer> {
+    /// Creates a new [`Node`] with the given identifier and content.
+    pub fn new(
+        id: T,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Node {
+            id,
+            content: content.into(),
+            children: Vec::new(),
+            is_expanded: false,
+            is_expandable: false,
+        }
+    }
+
+    /// Adds a child to the [`Node`].
+    ///
+    /// A [`Node`] with children is always expandable.
+    pub fn push(mut self, child: Self) -> Self {
+        self.children.push(child);
+        self.is_expandable = true;
+        self
+    }
+
+    /// Sets whether the [`Node`] is expanded or not.
+    ///
+    /// The children of a collapsed [`Node`] are not displayed.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets whether the [`Node`] can be expanded, even if it has no children
+    /// yet.
+    ///
+    /// This is useful to load the children of a [`Node`] lazily, once the
+    /// user expands it.
+    pub fn expandable(mut self, is_expandable: bool) -> Self {
+        self.is_expandable = is_expandable;
+        self
+    }
+}
+
+/// The local state of a [`TreeView`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the [`TreeView`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`TreeView`], enabling keyboard navigation.
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`TreeView`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    /// Snaps the scroll position to a relative amount.
+    ///
+    /// `0` represents scrollbar at the top, while `1` represents scrollbar at
+    /// the bottom.
+    pub fn snap_to(&mut self, percentage: f32) {
+        self.scrollable.snap_to(percentage);
+    }
+}
+
+/// A visible [`Node`] of a [`TreeView`], flattened.
+struct Row<'a, T, Message, Renderer> {
+    id: T,
+    content: Element<'a, Message, Renderer>,
+    depth: usize,
+    parent: Option<usize>,
+    is_expanded: bool,
+    is_expandable: bool,
+}
+
+fn flatten<'a, T, Message, Renderer>(
+    nodes: Vec<Node<'a, T, Message, Renderer>>,
+    depth: usize,
+    parent: Option<usize>,
+    rows: &mut Vec<Row<'a, T, Message, Renderer>>,
+) {
+    for node in nodes {
+        let index = rows.len();
+        let is_expanded = node.is_expanded && node.is_expandable;
+
+        rows.push(Row {
+            id: node.id,
+            content: node.content,
+            depth,
+            parent,
+            is_expanded,
+            is_expandable: node.is_expandable,
+        });
+
+        if is_expanded {
+            flatten(node.children, depth + 1, Some(index), rows);
+        }
+    }
+}
+
+/// Draws a boxed `+` or `-` sign, centered in the given bounds.
+fn draw_toggle<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    is_expanded: bool,
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    const SIZE: f32 = 9.0;
+    const THICKNESS: f32 = 1.0;
+
+    let x = (bounds.center_x() - SIZE / 2.0).round();
+    let y = (bounds.center_y() - SIZE / 2.0).round();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x,
+                y,
+                width: SIZE,
+                height: SIZE,
+            },
+            border_radius: 0.0,
+            border_width: THICKNESS,
+            border_color: color,
+        },
+        Color::TRANSPARENT,
+    );
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: x + 2.0,
+                y: y + (SIZE - THICKNESS) / 2.0,
+                width: SIZE - 4.0,
+                height: THICKNESS,
+            },
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        color,
+    );
+
+    if !is_expanded {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: x + (SIZE - THICKNESS) / 2.0,
+                    y: y + 2.0,
+                    width: THICKNESS,
+                    height: SIZE - 4.0,
+                },
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            color,
+        );
+    }
+}
+
+impl<'a, T, Message, Renderer> From<TreeView<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone + PartialEq,
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        tree_view: TreeView<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tree_view)
+    }
+}
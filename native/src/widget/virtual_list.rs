@@ -0,0 +1,558 @@
+//! Display a huge amount of rows by only building the visible ones.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::scrollable;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+use std::cell::{Cell, RefCell};
+use std::hash::Hash;
+use std::ops::Range;
+
+pub use iced_style::scrollable::StyleSheet;
+
+/// A scrollable list that only builds and lays out the rows intersecting its
+/// viewport.
+///
+/// The rows of a [`VirtualList`] are produced on demand by a closure that
+/// receives the index of the row to build.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{virtual_list, Text};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type VirtualList<'a, Message> = iced_native::widget::VirtualList<'a, Message, Null>;
+/// #
+/// # let mut state = virtual_list::State::new();
+/// #[derive(Debug, Clone)]
+/// enum Message {}
+///
+/// let list: VirtualList<Message> = VirtualList::new(
+///     &mut state,
+///     100_000,
+///     virtual_list::RowHeight::Fixed(20),
+///     |index| Text::new(format!("Row {}", index)).into(),
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<'a, Message, Renderer> {
+    state: &'a mut State,
+    row_count: usize,
+    row_height: RowHeight,
+    width: Length,
+    height: Length,
+    max_height: u32,
+    scrollbar_width: u16,
+    scrollbar_margin: u16,
+    scroller_width: u16,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    rows: RefCell<Rows<'a, Message, Renderer>>,
+    on_scroll: Option<Box<dyn Fn(f32) -> Message>>,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> VirtualList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`VirtualList`] with the given [`State`], the amount of
+    /// rows, their [`RowHeight`] and a function that builds the row at a given
+    /// index.
+    pub fn new(
+        state: &'a mut State,
+        row_count: usize,
+        row_height: RowHeight,
+        view: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        VirtualList {
+            state,
+            row_count,
+            row_height,
+            width: Length::Fill,
+            height: Length::Fill,
+            max_height: u32::MAX,
+            scrollbar_width: 10,
+            scrollbar_margin: 0,
+            scroller_width: 10,
+            view: Box::new(view),
+            rows: RefCell::new(Rows::default()),
+            on_scroll: None,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum height of the [`VirtualList`] in pixels.
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the scrollbar width of the [`VirtualList`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn scrollbar_width(mut self, scrollbar_width: u16) -> Self {
+        self.scrollbar_width = scrollbar_width.max(1);
+        self
+    }
+
+    /// Sets the scrollbar margin of the [`VirtualList`].
+    pub fn scrollbar_margin(mut self, scrollbar_margin: u16) -> Self {
+        self.scrollbar_margin = scrollbar_margin;
+        self
+    }
+
+    /// Sets the scroller width of the [`VirtualList`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn scroller_width(mut self, scroller_width: u16) -> Self {
+        self.scroller_width = scroller_width.max(1);
+        self
+    }
+
+    /// Sets a function to call when the [`VirtualList`] is scrolled.
+    ///
+    /// The function takes the new relative offset of the [`VirtualList`]
+    /// (e.g. `0` means top, while `1` means bottom).
+    pub fn on_scroll(mut self, f: impl Fn(f32) -> Message + 'static) -> Self {
+        self.on_scroll = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`VirtualList`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Returns the scrollbar of the [`VirtualList`].
+    fn scrollbar(&self) -> scrollable::Properties<'_, Message> {
+        scrollable::Properties {
+            scrollbar_width: self.scrollbar_width,
+            scrollbar_margin: self.scrollbar_margin,
+            scroller_width: self.scroller_width,
+            on_scroll: self.on_scroll.as_deref(),
+            style_sheet: self.style_sheet.as_ref(),
+        }
+    }
+
+    /// Builds the rows in the current visible range of the [`State`], unless
+    /// they are already built.
+    ///
+    /// The visible range is computed during layout. However, the layout of a
+    /// [`VirtualList`] may be cached between frames, in which case the rows
+    /// need to be rebuilt from the [`State`] alone.
+    fn build_rows(&self) {
+        let range = self.state.visible_range();
+        let mut rows = self.rows.borrow_mut();
+
+        if rows.range != range {
+            *rows = Rows {
+                elements: range
+                    .clone()
+                    .map(|index| (self.view)(index))
+                    .collect(),
+                range,
+            };
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for VirtualList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        let estimate = f32::from(self.row_height.estimate());
+        let estimated_height = estimate * self.row_count as f32;
+
+        let height = limits
+            .resolve(Size::new(limits.max().width, estimated_height))
+            .height;
+
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height,
+        };
+
+        let offset = self.state.scrollable.offset(
+            bounds,
+            Rectangle {
+                height: estimated_height,
+                ..bounds
+            },
+        ) as f32;
+
+        let is_at_bottom = offset + height >= estimated_height;
+
+        let row_limits = match self.row_height {
+            RowHeight::Fixed(fixed) => layout::Limits::new(
+                Size::new(limits.min().width, f32::from(fixed)),
+                Size::new(limits.max().width, f32::from(fixed)),
+            ),
+            RowHeight::Estimated(_) => layout::Limits::new(
+                Size::new(limits.min().width, 0.0),
+                Size::new(limits.max().width, f32::INFINITY),
+            ),
+        }
+        .with_direction(limits.direction());
+
+        let start = ((offset / estimate) as usize).min(self.row_count);
+
+        let mut elements = Vec::new();
+        let mut nodes = Vec::new();
+        let mut y = start as f32 * estimate;
+        let mut width: f32 = 0.0;
+
+        for index in start..self.row_count {
+            if y >= offset + height && !is_at_bottom {
+                break;
+            }
+
+            let element = (self.view)(index);
+            let mut node = element.layout(renderer, &row_limits);
+
+            node.move_to(Point::new(0.0, y));
+
+            y += node.size().height;
+            width = width.max(node.size().width);
+
+            elements.push(element);
+            nodes.push(node);
+        }
+
+        let range = start..start + elements.len();
+
+        self.state.visible.set((range.start, range.end));
+        *self.rows.borrow_mut() = Rows { range, elements };
+
+        let content_height = if is_at_bottom {
+            y
+        } else {
+            estimated_height.max(y)
+        };
+
+        let size = limits.resolve(Size::new(width, content_height));
+
+        let content = layout::Node::with_children(
+            Size::new(size.width, content_height),
+            nodes,
+        );
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.build_rows();
+
+        let rows = self.rows.get_mut();
+
+        let scrollbar = scrollable::Properties {
+            scrollbar_width: self.scrollbar_width,
+            scrollbar_margin: self.scrollbar_margin,
+            scroller_width: self.scroller_width,
+            on_scroll: self.on_scroll.as_deref(),
+            style_sheet: self.style_sheet.as_ref(),
+        };
+
+        let event_status = scrollable::update(
+            &mut self.state.scrollable,
+            &scrollbar,
+            event,
+            layout,
+            cursor_position,
+            shell,
+            |event, layout, cursor_position, shell| {
+                rows.elements
+                    .iter_mut()
+                    .zip(layout.children())
+                    .map(|(row, layout)| {
+                        row.widget.on_event(
+                            event.clone(),
+                            layout,
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            shell,
+                        )
+                    })
+                    .fold(event::Status::Ignored, event::Status::merge)
+            },
+        );
+
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+        let content_bounds = content.bounds();
+
+        let offset =
+            self.state.scrollable.offset(bounds, content_bounds) as f32;
+
+        let top = content
+            .children()
+            .next()
+            .map(|row| row.bounds().y - content_bounds.y)
+            .unwrap_or(0.0);
+
+        let bottom = content
+            .children()
+            .last()
+            .map(|row| {
+                let bounds = row.bounds();
+
+                bounds.y + bounds.height - content_bounds.y
+            })
+            .unwrap_or(0.0);
+
+        let is_range_exhausted = (offset < top
+            && self.state.visible.get().0 > 0)
+            || (offset + bounds.height > bottom
+                && self.state.visible.get().1 < self.row_count);
+
+        if is_range_exhausted {
+            shell.invalidate_layout();
+        }
+
+        event_status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.build_rows();
+
+        let rows = self.rows.borrow();
+
+        scrollable::mouse_interaction(
+            &self.state.scrollable,
+            &self.scrollbar(),
+            layout,
+            cursor_position,
+            |layout, cursor_position, viewport| {
+                rows.elements
+                    .iter()
+                    .zip(layout.children())
+                    .map(|(row, layout)| {
+                        row.widget.mouse_interaction(
+                            layout,
+                            cursor_position,
+                            viewport,
+                        )
+                    })
+                    .max()
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        self.build_rows();
+
+        let rows = self.rows.borrow();
+
+        scrollable::draw(
+            &self.state.scrollable,
+            &self.scrollbar(),
+            renderer,
+            layout,
+            cursor_position,
+            |renderer, layout, cursor_position, viewport| {
+                for (row, layout) in rows.elements.iter().zip(layout.children())
+                {
+                    row.draw(
+                        renderer,
+                        style,
+                        layout,
+                        cursor_position,
+                        viewport,
+                    );
+                }
+            },
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_height.hash(state);
+        self.row_count.hash(state);
+        self.row_height.hash(state);
+        self.state.scrollable.hash_offset(state);
+
+        self.build_rows();
+
+        for row in &self.rows.borrow().elements {
+            row.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.build_rows();
+
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let content_bounds = content_layout.bounds();
+        let offset = self.state.scrollable.offset(bounds, content_bounds);
+
+        self.rows
+            .get_mut()
+            .elements
+            .iter_mut()
+            .zip(content_layout.children())
+            .filter_map(|(row, layout)| row.widget.overlay(layout))
+            .next()
+            .map(|overlay| {
+                overlay.translate(Vector::new(0.0, -(offset as f32)))
+            })
+    }
+}
+
+/// The height of the rows of a [`VirtualList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RowHeight {
+    /// Every row has exactly the given height, in pixels.
+    Fixed(u16),
+
+    /// Rows have a variable height, estimated to be the given amount of
+    /// pixels on average.
+    ///
+    /// The estimate is used to compute the total height of the list and the
+    /// position of the rows that have not been built yet. Visible rows are
+    /// laid out with their actual height.
+    Estimated(u16),
+}
+
+impl RowHeight {
+    fn estimate(self) -> u16 {
+        match self {
+            RowHeight::Fixed(height) | RowHeight::Estimated(height) => {
+                height.max(1)
+            }
+        }
+    }
+}
+
+/// The local state of a [`VirtualList`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+    visible: Cell<(usize, usize)>,
+}
+
+impl State {
+    /// Creates a new [`State`] with the scrollbar located at the top.
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the range of row indices that were built during the latest
+    /// layout of the [`VirtualList`].
+    pub fn visible_range(&self) -> Range<usize> {
+        let (start, end) = self.visible.get();
+
+        start..end
+    }
+
+    /// Snaps the scroll position to a relative amount.
+    ///
+    /// `0` represents scrollbar at the top, while `1` represents scrollbar at
+    /// the bottom.
+    pub fn snap_to(&mut self, percentage: f32) {
+        self.scrollable.snap_to(percentage);
+    }
+
+    /// Returns whether the scroller is currently grabbed or not.
+    pub fn is_scroller_grabbed(&self) -> bool {
+        self.scrollable.is_scroller_grabbed()
+    }
+}
+
+struct Rows<'a, Message, Renderer> {
+    range: Range<usize>,
+    elements: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Default for Rows<'a, Message, Renderer> {
+    fn default() -> Self {
+        Rows {
+            range: 0..0,
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<VirtualList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        virtual_list: VirtualList<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(virtual_list)
+    }
+}
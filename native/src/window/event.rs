@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
@@ -25,6 +26,14 @@ pub enum Event {
     /// occurs.
     CloseRequested,
 
+    /// A window is about to be redrawn at the given [`Instant`].
+    ///
+    /// Widgets can animate by reacting to this event and requesting the next
+    /// frame with [`Shell::request_redraw`].
+    ///
+    /// [`Shell::request_redraw`]: crate::Shell::request_redraw
+    RedrawRequested(Instant),
+
     /// A window was focused.
     Focused,
 
@@ -251,6 +251,6 @@ pub use settings::Settings;
 pub use runtime::alignment;
 pub use runtime::futures;
 pub use runtime::{
-    Alignment, Background, Color, Command, Font, Length, Point, Rectangle,
-    Size, Subscription, Vector,
+    Alignment, Background, Color, Command, ContentFit, Direction, Font, Length,
+    Point, Rectangle, Size, Subscription, Vector,
 };
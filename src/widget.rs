@@ -16,9 +16,11 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, pick_list, progress_bar, radio,
-        rule, scrollable, slider, text_input, toggler, tooltip, Column, Row,
-        Space, Text,
+        button, checkbox, container, draggable, drop_target, file_drop_zone,
+        grid, mouse_area, pane_grid, pick_list, progress_bar, radio,
+        range_slider, reorderable_column, responsive, rich_text, rule,
+        scrollable, slider, table, tabs, text_input, toggler, tooltip,
+        tree_view, virtual_list, Column, Row, Space, Stack, Text,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -38,9 +40,9 @@ mod platform {
     #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
     pub mod image {
         //! Display images in your user interface.
-        pub use crate::runtime::image::Handle;
-        pub use crate::runtime::widget::image::viewer;
-        pub use crate::runtime::widget::image::{Image, Viewer};
+        pub use crate::runtime::image::{FilterMethod, Handle, Rotation};
+        pub use crate::runtime::widget::image::{animated, viewer};
+        pub use crate::runtime::widget::image::{AnimatedImage, Image, Viewer};
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
@@ -52,10 +54,16 @@ mod platform {
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
-        pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
-        radio::Radio, rule::Rule, scrollable::Scrollable, slider::Slider,
-        svg::Svg, text_input::TextInput, toggler::Toggler, tooltip::Tooltip,
+        button::Button, checkbox::Checkbox, container::Container,
+        draggable::Draggable, drop_target::DropTarget,
+        file_drop_zone::FileDropZone, grid::Grid, image::Image,
+        mouse_area::MouseArea, pane_grid::PaneGrid, pick_list::PickList,
+        progress_bar::ProgressBar, radio::Radio, range_slider::RangeSlider,
+        reorderable_column::ReorderableColumn, responsive::Responsive,
+        rich_text::RichText, rule::Rule, scrollable::Scrollable,
+        slider::Slider, svg::Svg, table::Table, tabs::Tabs,
+        text_input::TextInput, toggler::Toggler, tooltip::Tooltip,
+        tree_view::TreeView, virtual_list::VirtualList,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
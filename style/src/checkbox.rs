@@ -17,6 +17,45 @@ pub trait StyleSheet {
     fn active(&self, is_checked: bool) -> Style;
 
     fn hovered(&self, is_checked: bool) -> Style;
+
+    /// Produces the style of an active checkbox that is neither checked nor
+    /// unchecked.
+    fn active_indeterminate(&self) -> Style {
+        self.active(true)
+    }
+
+    /// Produces the style of a hovered checkbox that is neither checked nor
+    /// unchecked.
+    fn hovered_indeterminate(&self) -> Style {
+        self.hovered(true)
+    }
+
+    /// Produces the style of a disabled checkbox.
+    fn disabled(&self, is_checked: bool) -> Style {
+        let active = self.active(is_checked);
+
+        Style {
+            background: match active.background {
+                Background::Color(color) => Background::Color(Color {
+                    a: color.a * 0.5,
+                    ..color
+                }),
+            },
+            checkmark_color: Color {
+                a: active.checkmark_color.a * 0.5,
+                ..active.checkmark_color
+            },
+            text_color: Color {
+                a: active.text_color.a * 0.5,
+                ..active.text_color
+            },
+            border_color: Color {
+                a: active.border_color.a * 0.5,
+                ..active.border_color
+            },
+            ..active
+        }
+    }
 }
 
 struct Default;
@@ -0,0 +1,81 @@
+//! Accept content dropped from a draggable.
+use iced_core::{Background, Color};
+
+/// The appearance of a drop target.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: None,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a drop target.
+pub trait StyleSheet {
+    /// Produces the style of a drop target when nothing it accepts is being
+    /// dragged.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a drop target while something it accepts is
+    /// being dragged.
+    fn accepting(&self) -> Style {
+        self.active()
+    }
+
+    /// Produces the style of a drop target while something it accepts is
+    /// being dragged over it.
+    fn hovered(&self) -> Style {
+        self.accepting()
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn accepting(&self) -> Style {
+        Style {
+            border_radius: 2.0,
+            border_width: 1.0,
+            border_color: [0.6, 0.6, 1.0].into(),
+            ..self.active()
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            background: Some(Background::Color([0.4, 0.4, 1.0, 0.15].into())),
+            border_color: [0.4, 0.4, 1.0].into(),
+            ..self.accepting()
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
@@ -12,13 +12,18 @@ pub use iced_core::{Background, Color};
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod drop_target;
 pub mod menu;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod reorderable_column;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod table;
+pub mod tabs;
 pub mod text_input;
 pub mod toggler;
+pub mod tree_view;
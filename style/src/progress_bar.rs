@@ -12,6 +12,18 @@ pub struct Style {
 /// A set of rules that dictate the style of a progress bar.
 pub trait StyleSheet {
     fn style(&self) -> Style;
+
+    /// Produces the [`Background`] of the buffer of a progress bar.
+    ///
+    /// By default, it is the color of the bar with half its opacity.
+    fn buffer(&self) -> Background {
+        match self.style().bar {
+            Background::Color(color) => Background::Color(Color {
+                a: color.a * 0.5,
+                ..color
+            }),
+        }
+    }
 }
 
 struct Default;
@@ -0,0 +1,53 @@
+//! Let users reorder a column of items by dragging them.
+use iced_core::{Background, Color};
+
+/// The appearance of a reorderable column.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub handle_color: Color,
+    pub dragged_background: Option<Background>,
+    pub dragged_border_radius: f32,
+    pub dragged_border_width: f32,
+    pub dragged_border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            handle_color: [0.6, 0.6, 0.6].into(),
+            dragged_background: Some(Background::Color(Color::WHITE)),
+            dragged_border_radius: 2.0,
+            dragged_border_width: 1.0,
+            dragged_border_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a reorderable column.
+pub trait StyleSheet {
+    /// Produces the style of a reorderable column.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
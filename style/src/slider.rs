@@ -24,6 +24,28 @@ pub enum HandleShape {
     Rectangle { width: u16, border_radius: f32 },
 }
 
+/// The appearance of the tick marks of a slider.
+#[derive(Debug, Clone, Copy)]
+pub struct TickMarks {
+    pub color: Color,
+    pub width: f32,
+    pub length: f32,
+    pub text_color: Color,
+    pub text_size: u16,
+}
+
+impl std::default::Default for TickMarks {
+    fn default() -> Self {
+        Self {
+            color: Color::from_rgb(0.6, 0.6, 0.6),
+            width: 1.0,
+            length: 6.0,
+            text_color: Color::from_rgb(0.4, 0.4, 0.4),
+            text_size: 14,
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a slider.
 pub trait StyleSheet {
     /// Produces the style of an active slider.
@@ -34,6 +56,16 @@ pub trait StyleSheet {
 
     /// Produces the style of a slider that is being dragged.
     fn dragging(&self) -> Style;
+
+    /// Produces the style of a focused slider.
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
+    /// Produces the style of the tick marks of a slider.
+    fn tick_marks(&self) -> TickMarks {
+        TickMarks::default()
+    }
 }
 
 struct Default;
@@ -0,0 +1,71 @@
+//! Display tabular data with sortable, resizable columns.
+use iced_core::{Background, Color};
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub header_background: Option<Background>,
+    pub header_text_color: Option<Color>,
+    pub row_background: Option<Background>,
+    pub alternate_row_background: Option<Background>,
+    pub selected_row_background: Background,
+    pub selected_text_color: Option<Color>,
+    pub divider_width: f32,
+    pub divider_color: Color,
+    pub sort_indicator_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: None,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            header_background: Some(Background::Color(
+                [0.87, 0.87, 0.87].into(),
+            )),
+            header_text_color: None,
+            row_background: None,
+            alternate_row_background: Some(Background::Color(
+                [0.96, 0.96, 0.96].into(),
+            )),
+            selected_row_background: Background::Color([0.4, 0.4, 1.0].into()),
+            selected_text_color: Some(Color::WHITE),
+            divider_width: 1.0,
+            divider_color: [0.7, 0.7, 0.7].into(),
+            sort_indicator_color: [0.3, 0.3, 0.3].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a table.
+pub trait StyleSheet {
+    /// Produces the style of a table.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
@@ -0,0 +1,81 @@
+//! Switch between views using a bar of tabs.
+use iced_core::{Background, Color};
+
+/// The appearance of a tab.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub bar_background: Option<Background>,
+    pub background: Option<Background>,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub text_color: Color,
+    pub icon_color: Color,
+    pub close_color: Color,
+    pub scroll_button_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            bar_background: Some(Background::Color([0.87, 0.87, 0.87].into())),
+            background: None,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: Color::BLACK,
+            icon_color: Color::BLACK,
+            close_color: [0.4, 0.4, 0.4].into(),
+            scroll_button_color: [0.3, 0.3, 0.3].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a tab.
+pub trait StyleSheet {
+    fn active(&self, is_selected: bool) -> Style;
+
+    fn hovered(&self, is_selected: bool) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self, is_selected: bool) -> Style {
+        Style {
+            background: if is_selected {
+                Some(Background::Color(Color::WHITE))
+            } else {
+                None
+            },
+            ..Style::default()
+        }
+    }
+
+    fn hovered(&self, is_selected: bool) -> Style {
+        Style {
+            background: Some(Background::Color(if is_selected {
+                Color::WHITE
+            } else {
+                [0.93, 0.93, 0.93].into()
+            })),
+            close_color: Color::BLACK,
+            ..self.active(is_selected)
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
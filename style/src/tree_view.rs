@@ -0,0 +1,83 @@
+//! Display hierarchical data in an expandable tree.
+use iced_core::{Background, Color};
+
+/// The appearance of a tree view.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub hovered_background: Option<Background>,
+    pub selected_background: Background,
+    pub selected_text_color: Option<Color>,
+    pub toggle_color: Color,
+    pub guide_width: f32,
+    pub guide_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: None,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            hovered_background: None,
+            selected_background: Background::Color([0.4, 0.4, 1.0].into()),
+            selected_text_color: Some(Color::WHITE),
+            toggle_color: [0.3, 0.3, 0.3].into(),
+            guide_width: 1.0,
+            guide_color: [0.8, 0.8, 0.8].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a tree view.
+pub trait StyleSheet {
+    /// Produces the style of an active tree view.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a focused tree view.
+    fn focused(&self) -> Style {
+        self.active()
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            hovered_background: Some(Background::Color(
+                [0.0, 0.0, 0.0, 0.05].into(),
+            )),
+            selected_background: Background::Color([0.7, 0.7, 0.7].into()),
+            selected_text_color: None,
+            ..Style::default()
+        }
+    }
+
+    fn focused(&self) -> Style {
+        Style {
+            selected_background: Background::Color([0.4, 0.4, 1.0].into()),
+            selected_text_color: Some(Color::WHITE),
+            ..self.active()
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
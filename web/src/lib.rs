@@ -85,8 +85,8 @@ pub use iced_futures::executor;
 pub use iced_futures::futures;
 
 pub use iced_core::{
-    Alignment, Background, Color, Font, Length, Padding, Point, Rectangle,
-    Size, Vector,
+    Alignment, Background, Color, ContentFit, Direction, Font, Length, Padding,
+    Point, Rectangle, Size, Vector,
 };
 
 #[doc(no_inline)]
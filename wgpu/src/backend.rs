@@ -8,7 +8,7 @@ use iced_graphics::font;
 use iced_graphics::layer::Layer;
 use iced_graphics::{Primitive, Viewport};
 use iced_native::alignment;
-use iced_native::{Font, Size};
+use iced_native::{Font, Rectangle, Size};
 
 #[cfg(any(feature = "image_rs", feature = "svg"))]
 use crate::image;
@@ -170,7 +170,7 @@ impl Backend {
             }
         }
 
-        if !layer.text.is_empty() {
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
             for text in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
                 let text = wgpu_glyph::Section {
@@ -236,6 +236,39 @@ impl Backend {
                 self.text_pipeline.queue(text);
             }
 
+            for rich_text in layer.rich_text.iter() {
+                // Spans are laid out together, like the text above
+                let text = wgpu_glyph::Section {
+                    screen_position: (
+                        (rich_text.bounds.x * scale_factor).round(),
+                        (rich_text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (rich_text.bounds.width * scale_factor).ceil(),
+                        (rich_text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: rich_text
+                        .spans
+                        .iter()
+                        .map(|span| wgpu_glyph::Text {
+                            text: &span.content,
+                            scale: wgpu_glyph::ab_glyph::PxScale {
+                                x: span.size * scale_factor,
+                                y: span.size * scale_factor,
+                            },
+                            font_id: self.text_pipeline.find_font(span.font),
+                            extra: wgpu_glyph::Extra {
+                                color: span.color.into_linear(),
+                                z: 0.0,
+                            },
+                        })
+                        .collect(),
+                    ..Default::default()
+                };
+
+                self.text_pipeline.queue(text);
+            }
+
             self.text_pipeline.draw_queued(
                 device,
                 staging_belt,
@@ -278,6 +311,10 @@ impl backend::Text for Backend {
         self.text_pipeline.measure(contents, size, font, bounds)
     }
 
+    fn ascent(&self, size: f32, font: Font) -> f32 {
+        self.text_pipeline.ascent(size, font)
+    }
+
     fn hit_test(
         &self,
         contents: &str,
@@ -296,6 +333,22 @@ impl backend::Text for Backend {
             nearest_only,
         )
     }
+
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure_spans(spans, bounds)
+    }
+
+    fn span_regions(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> Vec<Vec<Rectangle>> {
+        self.text_pipeline.span_regions(spans, bounds)
+    }
 }
 
 #[cfg(feature = "image_rs")]
@@ -303,6 +356,13 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> (u32, u32) {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn frame_delays(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Vec<std::time::Duration> {
+        self.image_pipeline.frame_delays(handle)
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -10,7 +10,8 @@ use crate::Transformation;
 use atlas::Atlas;
 
 use iced_graphics::layer;
-use iced_native::Rectangle;
+use iced_native::image::FilterMethod;
+use iced_native::{Color, Rectangle};
 use std::cell::RefCell;
 use std::mem;
 
@@ -34,7 +35,8 @@ pub struct Pipeline {
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
-    constants: wgpu::BindGroup,
+    linear_constants: wgpu::BindGroup,
+    nearest_constants: wgpu::BindGroup,
     texture: wgpu::BindGroup,
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
@@ -45,15 +47,20 @@ impl Pipeline {
     pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
         use wgpu::util::DeviceExt;
 
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::FilterMode::Linear,
-            ..Default::default()
-        });
+        let create_sampler = |filter_mode| {
+            device.create_sampler(&wgpu::SamplerDescriptor {
+                address_mode_u: wgpu::AddressMode::ClampToEdge,
+                address_mode_v: wgpu::AddressMode::ClampToEdge,
+                address_mode_w: wgpu::AddressMode::ClampToEdge,
+                mag_filter: filter_mode,
+                min_filter: filter_mode,
+                mipmap_filter: filter_mode,
+                ..Default::default()
+            })
+        };
+
+        let linear_sampler = create_sampler(wgpu::FilterMode::Linear);
+        let nearest_sampler = create_sampler(wgpu::FilterMode::Nearest);
 
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -90,7 +97,7 @@ impl Pipeline {
             mapped_at_creation: false,
         });
 
-        let constant_bind_group =
+        let create_constants = |sampler: &wgpu::Sampler| {
             device.create_bind_group(&wgpu::BindGroupDescriptor {
                 label: Some("iced_wgpu::image constants bind group"),
                 layout: &constant_layout,
@@ -107,10 +114,14 @@ impl Pipeline {
                     },
                     wgpu::BindGroupEntry {
                         binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&sampler),
+                        resource: wgpu::BindingResource::Sampler(sampler),
                     },
                 ],
-            });
+            })
+        };
+
+        let linear_constants = create_constants(&linear_sampler);
+        let nearest_constants = create_constants(&nearest_sampler);
 
         let texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -170,6 +181,10 @@ impl Pipeline {
                                 3 => Float32x2,
                                 4 => Float32x2,
                                 5 => Sint32,
+                                6 => Float32x2,
+                                7 => Float32,
+                                8 => Float32x4,
+                                9 => Float32,
                             ),
                         },
                     ],
@@ -253,7 +268,8 @@ impl Pipeline {
             vertices,
             indices,
             instances,
-            constants: constant_bind_group,
+            linear_constants,
+            nearest_constants,
             texture,
             texture_version: texture_atlas.layer_count(),
             texture_layout,
@@ -269,6 +285,17 @@ impl Pipeline {
         memory.dimensions()
     }
 
+    #[cfg(feature = "image_rs")]
+    pub fn frame_delays(
+        &self,
+        handle: &image::Handle,
+    ) -> Vec<std::time::Duration> {
+        let mut cache = self.raster_cache.borrow_mut();
+        let delays = cache.frame_delays(handle);
+
+        delays.to_vec()
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
         let mut cache = self.vector_cache.borrow_mut();
@@ -296,12 +323,33 @@ impl Pipeline {
         #[cfg(feature = "svg")]
         let mut vector_cache = self.vector_cache.borrow_mut();
 
+        // Consecutive images sharing a filter method are drawn together, as
+        // each filter method needs its own sampler
+        let mut batches: Vec<(FilterMethod, usize)> = Vec::new();
+
         for image in images {
+            let filter_method = match image {
+                layer::Image::Raster { filter_method, .. } => *filter_method,
+                layer::Image::Vector { .. } => FilterMethod::Linear,
+            };
+
+            if batches.last().map(|(method, _)| *method) != Some(filter_method)
+            {
+                batches.push((filter_method, instances.len()));
+            }
+
             match &image {
                 #[cfg(feature = "image_rs")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    frame,
+                    rotation,
+                    bounds,
+                    ..
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
+                        *frame,
                         device,
                         encoder,
                         &mut self.texture_atlas,
@@ -309,6 +357,8 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
+                            rotation.radians(),
+                            None,
                             atlas_entry,
                             instances,
                         );
@@ -318,7 +368,11 @@ impl Pipeline {
                 layer::Image::Raster { .. } => {}
 
                 #[cfg(feature = "svg")]
-                layer::Image::Vector { handle, bounds } => {
+                layer::Image::Vector {
+                    handle,
+                    color,
+                    bounds,
+                } => {
                     let size = [bounds.width, bounds.height];
 
                     if let Some(atlas_entry) = vector_cache.upload(
@@ -332,6 +386,8 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             size,
+                            0.0,
+                            *color,
                             atlas_entry,
                             instances,
                         );
@@ -381,66 +437,77 @@ impl Pipeline {
             }));
         }
 
-        let mut i = 0;
-        let total = instances.len();
-
-        while i < total {
-            let end = (i + Instance::MAX).min(total);
-            let amount = end - i;
-
-            let mut instances_buffer = staging_belt.write_buffer(
-                encoder,
-                &self.instances,
-                0,
-                wgpu::BufferSize::new(
-                    (amount * std::mem::size_of::<Instance>()) as u64,
-                )
-                .unwrap(),
-                device,
-            );
-
-            instances_buffer.copy_from_slice(bytemuck::cast_slice(
-                &instances[i..i + amount],
-            ));
-
-            let mut render_pass =
-                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("iced_wgpu::image render pass"),
-                    color_attachments: &[wgpu::RenderPassColorAttachment {
-                        view: target,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: true,
-                        },
-                    }],
-                    depth_stencil_attachment: None,
-                });
-
-            render_pass.set_pipeline(&self.pipeline);
-            render_pass.set_bind_group(0, &self.constants, &[]);
-            render_pass.set_bind_group(1, &self.texture, &[]);
-            render_pass.set_index_buffer(
-                self.indices.slice(..),
-                wgpu::IndexFormat::Uint16,
-            );
-            render_pass.set_vertex_buffer(0, self.vertices.slice(..));
-            render_pass.set_vertex_buffer(1, self.instances.slice(..));
-
-            render_pass.set_scissor_rect(
-                bounds.x,
-                bounds.y,
-                bounds.width,
-                bounds.height,
-            );
-
-            render_pass.draw_indexed(
-                0..QUAD_INDICES.len() as u32,
-                0,
-                0..amount as u32,
-            );
-
-            i += Instance::MAX;
+        for (index, (filter_method, start)) in batches.iter().enumerate() {
+            let total = batches
+                .get(index + 1)
+                .map(|(_, start)| *start)
+                .unwrap_or(instances.len());
+
+            let constants = match filter_method {
+                FilterMethod::Linear => &self.linear_constants,
+                FilterMethod::Nearest => &self.nearest_constants,
+            };
+
+            let mut i = *start;
+
+            while i < total {
+                let end = (i + Instance::MAX).min(total);
+                let amount = end - i;
+
+                let mut instances_buffer = staging_belt.write_buffer(
+                    encoder,
+                    &self.instances,
+                    0,
+                    wgpu::BufferSize::new(
+                        (amount * std::mem::size_of::<Instance>()) as u64,
+                    )
+                    .unwrap(),
+                    device,
+                );
+
+                instances_buffer.copy_from_slice(bytemuck::cast_slice(
+                    &instances[i..i + amount],
+                ));
+
+                let mut render_pass =
+                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                        label: Some("iced_wgpu::image render pass"),
+                        color_attachments: &[wgpu::RenderPassColorAttachment {
+                            view: target,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load,
+                                store: true,
+                            },
+                        }],
+                        depth_stencil_attachment: None,
+                    });
+
+                render_pass.set_pipeline(&self.pipeline);
+                render_pass.set_bind_group(0, constants, &[]);
+                render_pass.set_bind_group(1, &self.texture, &[]);
+                render_pass.set_index_buffer(
+                    self.indices.slice(..),
+                    wgpu::IndexFormat::Uint16,
+                );
+                render_pass.set_vertex_buffer(0, self.vertices.slice(..));
+                render_pass.set_vertex_buffer(1, self.instances.slice(..));
+
+                render_pass.set_scissor_rect(
+                    bounds.x,
+                    bounds.y,
+                    bounds.width,
+                    bounds.height,
+                );
+
+                render_pass.draw_indexed(
+                    0..QUAD_INDICES.len() as u32,
+                    0,
+                    0..amount as u32,
+                );
+
+                i += Instance::MAX;
+            }
         }
     }
 
@@ -484,6 +551,10 @@ struct Instance {
     _position_in_atlas: [f32; 2],
     _size_in_atlas: [f32; 2],
     _layer: u32,
+    _center: [f32; 2],
+    _rotation: f32,
+    _color: [f32; 4],
+    _is_tinted: f32,
 }
 
 impl Instance {
@@ -499,12 +570,28 @@ struct Uniforms {
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
+    rotation: f32,
+    tint: Option<Color>,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
+    // Every fragment of the image rotates around the center of the image
+    let center = [
+        image_position[0] + image_size[0] / 2.0,
+        image_position[1] + image_size[1] / 2.0,
+    ];
+
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
+            add_instance(
+                image_position,
+                image_size,
+                center,
+                rotation,
+                tint,
+                allocation,
+                instances,
+            );
         }
         atlas::Entry::Fragmented { fragments, size } => {
             let scaling_x = image_size[0] / size.0 as f32;
@@ -527,7 +614,10 @@ fn add_instances(
                     fragment_height as f32 * scaling_y,
                 ];
 
-                add_instance(position, size, allocation, instances);
+                add_instance(
+                    position, size, center, rotation, tint, allocation,
+                    instances,
+                );
             }
         }
     }
@@ -537,6 +627,9 @@ fn add_instances(
 fn add_instance(
     position: [f32; 2],
     size: [f32; 2],
+    center: [f32; 2],
+    rotation: f32,
+    tint: Option<Color>,
     allocation: &atlas::Allocation,
     instances: &mut Vec<Instance>,
 ) {
@@ -556,6 +649,10 @@ fn add_instance(
             (height as f32 - 1.0) / atlas::SIZE as f32,
         ],
         _layer: layer as u32,
+        _center: center,
+        _rotation: rotation,
+        _color: tint.map(Color::into_linear).unwrap_or([0.0; 4]),
+        _is_tinted: if tint.is_some() { 1.0 } else { 0.0 },
     };
 
     instances.push(instance);
@@ -1,12 +1,19 @@
 use crate::image::atlas::{self, Atlas};
 use iced_native::image;
 use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
 use bitflags::bitflags;
 
+/// The pixels of a decoded image, in BGRA order.
+///
+/// `image` does not have a BGRA pixel type, so the channels are stored in an
+/// RGBA buffer and swapped with [`to_bgra8`].
+type Bgra8 = ::image_rs::ImageBuffer<::image_rs::Rgba<u8>, Vec<u8>>;
+
 #[derive(Debug)]
 pub enum Memory {
-    Host(::image_rs::ImageBuffer<::image_rs::Bgra<u8>, Vec<u8>>),
+    Host(Bgra8),
     Device(atlas::Entry),
     NotFound,
     Invalid,
@@ -23,9 +30,18 @@ impl Memory {
     }
 }
 
+/// The decoded frames of an image.
+///
+/// An image that is not animated only has a single frame.
+#[derive(Debug)]
+struct Entry {
+    frames: Vec<Memory>,
+    delays: Vec<Duration>,
+}
+
 #[derive(Debug)]
 pub struct Cache {
-    map: HashMap<u64, Memory>,
+    map: HashMap<u64, Entry>,
     hits: HashSet<u64>,
 }
 
@@ -38,11 +54,46 @@ impl Cache {
     }
 
     pub fn load(&mut self, handle: &image::Handle) -> &mut Memory {
+        self.load_frame(handle, 0)
+    }
+
+    pub fn load_frame(
+        &mut self,
+        handle: &image::Handle,
+        frame: usize,
+    ) -> &mut Memory {
+        let entry = self.load_entry(handle);
+        let last = entry.frames.len() - 1;
+
+        &mut entry.frames[frame.min(last)]
+    }
+
+    pub fn frame_delays(&mut self, handle: &image::Handle) -> &[Duration] {
+        &self.load_entry(handle).delays
+    }
+
+    fn load_entry(&mut self, handle: &image::Handle) -> &mut Entry {
         if self.contains(handle) {
             return self.get(handle).unwrap();
         }
 
-        let memory = match handle.data() {
+        let entry = match decode_animation(handle.data()) {
+            Some((frames, delays)) => Entry {
+                frames: frames.into_iter().map(Memory::Host).collect(),
+                delays,
+            },
+            None => Entry {
+                frames: vec![Self::decode(handle.data())],
+                delays: Vec::new(),
+            },
+        };
+
+        self.insert(handle, entry);
+        self.get(handle).unwrap()
+    }
+
+    fn decode(data: &image::Data) -> Memory {
+        match data {
             image::Data::Path(path) => {
                 if let Ok(image) = image_rs::open(path) {
                     let operation = std::fs::File::open(path)
@@ -53,7 +104,7 @@ impl Cache {
                         })
                         .unwrap_or_else(Operation::empty);
 
-                    Memory::Host(operation.perform(image.to_bgra8()))
+                    Memory::Host(operation.perform(to_bgra8(image)))
                 } else {
                     Memory::NotFound
                 }
@@ -65,7 +116,7 @@ impl Cache {
                             .ok()
                             .unwrap_or_else(Operation::empty);
 
-                    Memory::Host(operation.perform(image.to_bgra8()))
+                    Memory::Host(operation.perform(to_bgra8(image)))
                 } else {
                     Memory::Invalid
                 }
@@ -85,20 +136,22 @@ impl Cache {
                     Memory::Invalid
                 }
             }
-        };
-
-        self.insert(handle, memory);
-        self.get(handle).unwrap()
+        }
     }
 
+    /// Uploads the given frame of an image to the [`Atlas`], if it is not
+    /// there already.
+    ///
+    /// Only the frames of an animated image that are drawn are uploaded.
     pub fn upload(
         &mut self,
         handle: &image::Handle,
+        frame: usize,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         atlas: &mut Atlas,
     ) -> Option<&atlas::Entry> {
-        let memory = self.load(handle);
+        let memory = self.load_frame(handle, frame);
 
         if let Memory::Host(image) = memory {
             let (width, height) = image.dimensions();
@@ -118,12 +171,14 @@ impl Cache {
     pub fn trim(&mut self, atlas: &mut Atlas) {
         let hits = &self.hits;
 
-        self.map.retain(|k, memory| {
+        self.map.retain(|k, entry| {
             let retain = hits.contains(k);
 
             if !retain {
-                if let Memory::Device(entry) = memory {
-                    atlas.remove(entry);
+                for memory in &entry.frames {
+                    if let Memory::Device(allocation) = memory {
+                        atlas.remove(allocation);
+                    }
                 }
             }
 
@@ -133,14 +188,14 @@ impl Cache {
         self.hits.clear();
     }
 
-    fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory> {
+    fn get(&mut self, handle: &image::Handle) -> Option<&mut Entry> {
         let _ = self.hits.insert(handle.id());
 
         self.map.get_mut(&handle.id())
     }
 
-    fn insert(&mut self, handle: &image::Handle, memory: Memory) {
-        let _ = self.map.insert(handle.id(), memory);
+    fn insert(&mut self, handle: &image::Handle, entry: Entry) {
+        let _ = self.map.insert(handle.id(), entry);
     }
 
     fn contains(&self, handle: &image::Handle) -> bool {
@@ -148,6 +203,119 @@ impl Cache {
     }
 }
 
+/// A decoded frame of an animated image.
+type Frame = Bgra8;
+
+/// Converts a decoded image to BGRA.
+fn to_bgra8(image: image_rs::DynamicImage) -> Bgra8 {
+    let mut image = image.into_rgba8();
+
+    for pixel in image.pixels_mut() {
+        pixel.0.swap(0, 2);
+    }
+
+    image
+}
+
+/// The delay used for frames that do not specify a meaningful one, like
+/// browsers do.
+#[cfg(any(feature = "gif", feature = "png", feature = "webp"))]
+const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+/// The shortest frame delay that is respected.
+#[cfg(any(feature = "gif", feature = "png", feature = "webp"))]
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(10);
+
+/// Decodes all the frames of an animated GIF, PNG or WebP, with their delays.
+///
+/// Returns `None` if the image is not animated.
+#[cfg(any(feature = "gif", feature = "png", feature = "webp"))]
+fn decode_animation(data: &image::Data) -> Option<(Vec<Frame>, Vec<Duration>)> {
+    use image_rs::{AnimationDecoder, ImageFormat};
+    use std::borrow::Cow;
+
+    let bytes: Cow<'_, [u8]> = match data {
+        image::Data::Path(path) => match ImageFormat::from_path(path) {
+            Ok(ImageFormat::Gif)
+            | Ok(ImageFormat::Png)
+            | Ok(ImageFormat::WebP) => Cow::Owned(std::fs::read(path).ok()?),
+            _ => return None,
+        },
+        image::Data::Bytes(bytes) => Cow::Borrowed(bytes),
+        image::Data::Pixels { .. } => return None,
+    };
+
+    let frames = match image_rs::guess_format(&bytes).ok()? {
+        #[cfg(feature = "gif")]
+        ImageFormat::Gif => {
+            image_rs::codecs::gif::GifDecoder::new(std::io::Cursor::new(&bytes))
+                .ok()?
+                .into_frames()
+                .collect_frames()
+                .ok()?
+        }
+        // Only PNGs with an animation control chunk are animated
+        #[cfg(feature = "png")]
+        ImageFormat::Png if bytes.windows(4).any(|chunk| chunk == b"acTL") => {
+            image_rs::codecs::png::PngDecoder::new(std::io::Cursor::new(&bytes))
+                .ok()?
+                .apng()
+                .into_frames()
+                .collect_frames()
+                .ok()?
+        }
+        #[cfg(feature = "webp")]
+        ImageFormat::WebP => image_rs::codecs::webp::WebPDecoder::new(
+            std::io::Cursor::new(&bytes),
+        )
+        .ok()?
+        .into_frames()
+        .collect_frames()
+        .ok()?,
+        _ => return None,
+    };
+
+    if frames.len() < 2 {
+        return None;
+    }
+
+    Some(
+        frames
+            .into_iter()
+            .map(|frame| {
+                let (numerator, denominator) = frame.delay().numer_denom_ms();
+
+                let delay = if denominator == 0 {
+                    Duration::default()
+                } else {
+                    Duration::from_secs_f64(
+                        f64::from(numerator) / f64::from(denominator) / 1000.0,
+                    )
+                };
+
+                let delay = if delay <= MIN_FRAME_DELAY {
+                    DEFAULT_FRAME_DELAY
+                } else {
+                    delay
+                };
+
+                let image = to_bgra8(image_rs::DynamicImage::ImageRgba8(
+                    frame.into_buffer(),
+                ));
+
+                (image, delay)
+            })
+            .unzip(),
+    )
+}
+
+#[cfg(not(any(feature = "gif", feature = "png", feature = "webp")))]
+fn decode_animation(
+    _data: &image::Data,
+) -> Option<(Vec<Frame>, Vec<Duration>)> {
+    None
+}
+
 bitflags! {
     struct Operation: u8 {
         const FLIP_HORIZONTALLY = 0b001;
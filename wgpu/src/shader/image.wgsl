@@ -14,12 +14,18 @@ struct VertexInput {
     [[location(3)]] atlas_pos: vec2<f32>;
     [[location(4)]] atlas_scale: vec2<f32>;
     [[location(5)]] layer: i32;
+    [[location(6)]] center: vec2<f32>;
+    [[location(7)]] rotation: f32;
+    [[location(8)]] color: vec4<f32>;
+    [[location(9)]] is_tinted: f32;
 };
 
 struct VertexOutput {
     [[builtin(position)]] position: vec4<f32>;
     [[location(0)]] uv: vec2<f32>;
     [[location(1)]] layer: f32; // this should be an i32, but naga currently reads that as requiring interpolation.
+    [[location(2)]] color: vec4<f32>;
+    [[location(3)]] is_tinted: f32;
 };
 
 [[stage(vertex)]]
@@ -28,6 +34,8 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
     out.uv = vec2<f32>(input.v_pos * input.atlas_scale + input.atlas_pos);
     out.layer = f32(input.layer);
+    out.color = input.color;
+    out.is_tinted = input.is_tinted;
 
     var transform: mat4x4<f32> = mat4x4<f32>(
         vec4<f32>(input.scale.x, 0.0, 0.0, 0.0),
@@ -36,12 +44,33 @@ fn vs_main(input: VertexInput) -> VertexOutput {
         vec4<f32>(input.pos, 0.0, 1.0)
     );
 
-    out.position = globals.transform * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
+    // Rotate clockwise around the center of the image
+    var c: f32 = cos(input.rotation);
+    var s: f32 = sin(input.rotation);
+
+    var rotation: mat4x4<f32> = mat4x4<f32>(
+        vec4<f32>(c, s, 0.0, 0.0),
+        vec4<f32>(-s, c, 0.0, 0.0),
+        vec4<f32>(0.0, 0.0, 1.0, 0.0),
+        vec4<f32>(
+            input.center.x - c * input.center.x + s * input.center.y,
+            input.center.y - s * input.center.x - c * input.center.y,
+            0.0,
+            1.0
+        )
+    );
+
+    out.position = globals.transform * rotation * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
 
     return out;
 }
 
 [[stage(fragment)]]
 fn fs_main(input: VertexOutput) -> [[location(0)]] vec4<f32> {
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+    var sample: vec4<f32> = textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+
+    // A tinted image keeps its transparency, but takes the color of its tint
+    var tinted: vec4<f32> = vec4<f32>(input.color.rgb, sample.a * input.color.a);
+
+    return sample * (1.0 - input.is_tinted) + tinted * input.is_tinted;
 }
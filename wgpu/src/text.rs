@@ -5,7 +5,7 @@ use iced_graphics::font;
 use std::{cell::RefCell, collections::HashMap};
 use wgpu_glyph::ab_glyph;
 
-pub use iced_native::text::Hit;
+pub use iced_native::text::{Hit, Span};
 
 #[derive(Debug)]
 pub struct Pipeline {
@@ -121,6 +121,16 @@ impl Pipeline {
         }
     }
 
+    pub fn ascent(&self, size: f32, font: iced_native::Font) -> f32 {
+        use wgpu_glyph::ab_glyph::{Font, ScaleFont};
+
+        let wgpu_glyph::FontId(font_id) = self.find_font(font);
+
+        self.measure_brush.borrow().fonts()[font_id]
+            .as_scaled(size)
+            .ascent()
+    }
+
     pub fn hit_test(
         &self,
         content: &str,
@@ -209,6 +219,107 @@ impl Pipeline {
         })
     }
 
+    pub fn measure_spans(
+        &self,
+        spans: &[Span<'_, iced_native::Font>],
+        bounds: iced_native::Size,
+    ) -> (f32, f32) {
+        use wgpu_glyph::GlyphCruncher;
+
+        let section = self.spans_section(spans, bounds);
+
+        if let Some(bounds) =
+            self.measure_brush.borrow_mut().glyph_bounds(section)
+        {
+            (bounds.width().ceil(), bounds.height().ceil())
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
+    pub fn span_regions(
+        &self,
+        spans: &[Span<'_, iced_native::Font>],
+        bounds: iced_native::Size,
+    ) -> Vec<Vec<iced_native::Rectangle>> {
+        use wgpu_glyph::ab_glyph::{Font, ScaleFont};
+        use wgpu_glyph::GlyphCruncher;
+
+        let section = self.spans_section(spans, bounds);
+
+        let mut mb = self.measure_brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        let fonts: Vec<_> = section
+            .text
+            .iter()
+            .map(|text| {
+                let wgpu_glyph::FontId(font_id) = text.font_id;
+
+                mb.fonts()[font_id].clone().into_scaled(text.scale)
+            })
+            .collect();
+
+        let mut regions = vec![Vec::new(); spans.len()];
+
+        for wgpu_glyph::SectionGlyph {
+            section_index,
+            glyph,
+            ..
+        } in mb.glyphs(section)
+        {
+            let font = &fonts[*section_index];
+
+            let glyph_bounds = iced_native::Rectangle::new(
+                iced_native::Point::new(
+                    glyph.position.x,
+                    glyph.position.y - font.ascent(),
+                ),
+                iced_native::Size::new(
+                    font.h_advance(glyph.id),
+                    font.ascent() - font.descent(),
+                ),
+            );
+
+            let span_regions: &mut Vec<iced_native::Rectangle> =
+                &mut regions[*section_index];
+
+            // Glyphs of a span in the same line are merged in one region
+            match span_regions.last_mut() {
+                Some(region)
+                    if (region.y - glyph_bounds.y).abs() < 0.5
+                        && glyph_bounds.x >= region.x =>
+                {
+                    region.width =
+                        glyph_bounds.x + glyph_bounds.width - region.x;
+                }
+                _ => span_regions.push(glyph_bounds),
+            }
+        }
+
+        regions
+    }
+
+    fn spans_section<'a>(
+        &self,
+        spans: &[Span<'a, iced_native::Font>],
+        bounds: iced_native::Size,
+    ) -> wgpu_glyph::Section<'a> {
+        wgpu_glyph::Section {
+            bounds: (bounds.width, bounds.height),
+            text: spans
+                .iter()
+                .map(|span| wgpu_glyph::Text {
+                    text: span.content,
+                    scale: span.size.into(),
+                    font_id: self.find_font(span.font),
+                    extra: wgpu_glyph::Extra::default(),
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
     pub fn trim_measurement_cache(&mut self) {
         // TODO: We should probably use a `GlyphCalculator` for this. However,
         // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
@@ -12,16 +12,29 @@ use crate::Renderer;
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod draggable;
+pub mod drop_target;
+pub mod file_drop_zone;
+pub mod grid;
+pub mod mouse_area;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod range_slider;
+pub mod reorderable_column;
+pub mod responsive;
+pub mod rich_text;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod table;
+pub mod tabs;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
+pub mod tree_view;
+pub mod virtual_list;
 
 #[doc(no_inline)]
 pub use button::Button;
@@ -30,6 +43,16 @@ pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_target::DropTarget;
+#[doc(no_inline)]
+pub use file_drop_zone::FileDropZone;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use mouse_area::MouseArea;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -38,17 +61,33 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
+pub use reorderable_column::ReorderableColumn;
+#[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
+#[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
@@ -75,5 +114,8 @@ pub type Column<'a, Message> =
 /// A container that distributes its contents horizontally.
 pub type Row<'a, Message> = iced_native::widget::Row<'a, Message, Renderer>;
 
+/// A container that displays its contents on top of each other.
+pub type Stack<'a, Message> = iced_native::widget::Stack<'a, Message, Renderer>;
+
 /// A paragraph of text.
 pub type Text = iced_native::widget::Text<Renderer>;
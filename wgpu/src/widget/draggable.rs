@@ -0,0 +1,10 @@
+//! Drag content and drop it on a drop target.
+use crate::Renderer;
+
+pub use iced_graphics::draggable::State;
+
+/// A wrapper that lets its content be dragged around, carrying a payload.
+///
+/// This is an alias of an `iced_native` draggable with a default `Renderer`.
+pub type Draggable<'a, T, Message> =
+    iced_native::widget::Draggable<'a, T, Message, Renderer>;
@@ -0,0 +1,11 @@
+//! Accept content dropped from a draggable.
+use crate::Renderer;
+
+pub use iced_graphics::drop_target::{Style, StyleSheet};
+
+/// A wrapper that accepts the payload of a draggable dropped on it.
+///
+/// This is an alias of an `iced_native` drop target with a default
+/// `Renderer`.
+pub type DropTarget<'a, T, Message> =
+    iced_native::widget::DropTarget<'a, T, Message, Renderer>;
@@ -0,0 +1,11 @@
+//! Distribute content in a grid of columns and rows.
+use crate::Renderer;
+
+/// A container that distributes its contents in a grid of columns and rows.
+///
+/// This is an alias of an `iced_native` grid with a default `Renderer`.
+pub type Grid<'a, Message> = iced_native::widget::Grid<'a, Message, Renderer>;
+
+/// The content of a grid with an explicit placement, span or alignment.
+pub type Cell<'a, Message> =
+    iced_native::widget::grid::Cell<'a, Message, Renderer>;
@@ -1,5 +1,7 @@
 //! Display an interactive selector of a single value from a range of values.
 //!
 //! A [`Slider`] has some local [`State`].
-pub use iced_graphics::slider::{Handle, HandleShape, Style, StyleSheet};
+pub use iced_graphics::slider::{
+    Handle, HandleShape, Orientation, Style, StyleSheet, TickMark, TickMarks,
+};
 pub use iced_native::widget::slider::{Slider, State};
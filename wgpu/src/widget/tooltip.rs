@@ -3,4 +3,4 @@
 pub type Tooltip<'a, Message> =
     iced_native::widget::Tooltip<'a, Message, crate::Renderer>;
 
-pub use iced_native::widget::tooltip::Position;
+pub use iced_native::widget::tooltip::{Position, State};
@@ -0,0 +1,11 @@
+//! Display hierarchical data in an expandable tree.
+use crate::Renderer;
+
+pub use iced_graphics::tree_view::{Node, State, Style, StyleSheet};
+
+/// A widget displaying a hierarchy of nodes that can be expanded and
+/// collapsed.
+///
+/// This is an alias of an `iced_native` tree view with a default `Renderer`.
+pub type TreeView<'a, T, Message> =
+    iced_native::widget::TreeView<'a, T, Message, Renderer>;
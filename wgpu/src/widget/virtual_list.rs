@@ -0,0 +1,13 @@
+//! Display a huge amount of rows by only building the visible ones.
+use crate::Renderer;
+
+pub use iced_graphics::virtual_list::{RowHeight, State};
+pub use iced_graphics::virtual_list::{Scrollbar, Scroller, StyleSheet};
+
+/// A scrollable list that only builds and lays out the rows intersecting its
+/// viewport.
+///
+/// This is an alias of an `iced_native` virtual list with a default
+/// `Renderer`.
+pub type VirtualList<'a, Message> =
+    iced_native::widget::VirtualList<'a, Message, Renderer>;
@@ -18,6 +18,7 @@ use iced_native::program::Program;
 use iced_native::{Cache, UserInterface};
 
 use std::mem::ManuallyDrop;
+use std::time::Instant;
 
 /// An interactive, native cross-platform application.
 ///
@@ -163,6 +164,7 @@ where
     let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
 
     let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -173,6 +175,7 @@ where
         proxy,
         debug,
         receiver,
+        control_sender,
         window,
         settings.exit_on_close_request,
     ));
@@ -207,7 +210,18 @@ where
             let poll = instance.as_mut().poll(&mut context);
 
             *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
+                task::Poll::Pending => {
+                    let mut new_control_flow = ControlFlow::Wait;
+
+                    // The latest control flow sent by the instance prevails
+                    while let Ok(Some(control_flow)) =
+                        control_receiver.try_next()
+                    {
+                        new_control_flow = control_flow;
+                    }
+
+                    new_control_flow
+                }
                 task::Poll::Ready(_) => ControlFlow::Exit,
             };
         }
@@ -225,6 +239,7 @@ async fn run_instance<A, E, C>(
     mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<winit::event::Event<'_, A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<winit::event_loop::ControlFlow>,
     window: winit::window::Window,
     exit_on_close_request: bool,
 ) where
@@ -259,13 +274,25 @@ async fn run_instance<A, E, C>(
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    let mut redraw_request: Option<Instant> = None;
 
     debug.startup_finished();
 
-    while let Some(event) = receiver.next().await {
+    'run: while let Some(event) = receiver.next().await {
+        // Keep waiting for the frame requested by the widgets, unless the
+        // event changes it.
+        control_sender
+            .start_send(control_flow(redraw_request))
+            .expect("Send control flow");
+
         match event {
             event::Event::MainEventsCleared => {
-                if events.is_empty() && messages.is_empty() {
+                let is_redraw_due = matches!(
+                    redraw_request,
+                    Some(at) if at <= Instant::now()
+                );
+
+                if events.is_empty() && messages.is_empty() && !is_redraw_due {
                     continue;
                 }
 
@@ -281,43 +308,82 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
+                // The user interface may be rebuilt before drawing, so we
+                // keep the frames requested while processing the events.
+                redraw_request = user_interface.redraw_request();
+
                 for event in events.drain(..).zip(statuses.into_iter()) {
                     runtime.broadcast(event);
                 }
 
-                if !messages.is_empty() {
-                    let cache =
-                        ManuallyDrop::into_inner(user_interface).into_cache();
+                // Let widgets animate before drawing, and wait until the
+                // next frame they request, if any. The messages produced
+                // while animating are handled before drawing as well.
+                loop {
+                    if !messages.is_empty() {
+                        let cache = ManuallyDrop::into_inner(user_interface)
+                            .into_cache();
+
+                        // Update application
+                        update(
+                            &mut application,
+                            &mut runtime,
+                            &mut clipboard,
+                            &mut proxy,
+                            &mut debug,
+                            &mut messages,
+                            &window,
+                        );
+
+                        // Update window
+                        state.synchronize(&application, &window);
+
+                        let should_exit = application.should_exit();
+
+                        user_interface =
+                            ManuallyDrop::new(build_user_interface(
+                                &mut application,
+                                cache,
+                                &mut renderer,
+                                state.logical_size(),
+                                &mut debug,
+                            ));
+
+                        if should_exit {
+                            break 'run;
+                        }
+                    }
+
+                    let redraw_event = iced_native::Event::Window(
+                        iced_native::window::Event::RedrawRequested(
+                            Instant::now(),
+                        ),
+                    );
 
-                    // Update application
-                    update(
-                        &mut application,
-                        &mut runtime,
+                    let _ = user_interface.update(
+                        &[redraw_event],
+                        state.cursor_position(),
+                        &mut renderer,
                         &mut clipboard,
-                        &mut proxy,
-                        &mut debug,
                         &mut messages,
-                        &window,
                     );
 
-                    // Update window
-                    state.synchronize(&application, &window);
-
-                    let should_exit = application.should_exit();
-
-                    user_interface = ManuallyDrop::new(build_user_interface(
-                        &mut application,
-                        cache,
-                        &mut renderer,
-                        state.logical_size(),
-                        &mut debug,
-                    ));
+                    redraw_request =
+                        match (redraw_request, user_interface.redraw_request())
+                        {
+                            (Some(a), Some(b)) => Some(a.min(b)),
+                            (a, b) => a.or(b),
+                        };
 
-                    if should_exit {
+                    if messages.is_empty() {
                         break;
                     }
                 }
 
+                control_sender
+                    .start_send(control_flow(redraw_request))
+                    .expect("Send control flow");
+
                 debug.draw_started();
                 let new_mouse_interaction =
                     user_interface.draw(&mut renderer, state.cursor_position());
@@ -397,9 +463,6 @@ async fn run_instance<A, E, C>(
                 ) {
                     Ok(()) => {
                         debug.render_finished();
-
-                        // TODO: Handle animations!
-                        // Maybe we can use `ControlFlow::WaitUntil` for this.
                     }
                     Err(error) => match error {
                         // This is an unrecoverable error.
@@ -443,6 +506,19 @@ async fn run_instance<A, E, C>(
     drop(ManuallyDrop::into_inner(user_interface));
 }
 
+/// Returns the [`ControlFlow`] that waits for the frame requested by the
+/// widgets of an [`Application`], if any.
+///
+/// [`ControlFlow`]: winit::event_loop::ControlFlow
+pub fn control_flow(
+    redraw_request: Option<Instant>,
+) -> winit::event_loop::ControlFlow {
+    match redraw_request {
+        Some(at) => winit::event_loop::ControlFlow::WaitUntil(at),
+        None => winit::event_loop::ControlFlow::Wait,
+    }
+}
+
 /// Returns true if the provided event should cause an [`Application`] to
 /// exit.
 pub fn requests_exit(
@@ -15,6 +15,7 @@ pub struct State<A: Application> {
     viewport: Viewport,
     viewport_version: usize,
     cursor_position: winit::dpi::PhysicalPosition<f64>,
+    is_hovering_files: bool,
     modifiers: winit::event::ModifiersState,
     application: PhantomData<A>,
 }
@@ -45,6 +46,7 @@ impl<A: Application> State<A> {
             viewport_version: 0,
             // TODO: Encode cursor availability in the type-system
             cursor_position: winit::dpi::PhysicalPosition::new(-1.0, -1.0),
+            is_hovering_files: false,
             modifiers: winit::event::ModifiersState::default(),
             application: PhantomData,
         }
@@ -134,7 +136,24 @@ impl<A: Application> State<A> {
             }) => {
                 self.cursor_position = *position;
             }
-            WindowEvent::CursorLeft { .. } => {
+            WindowEvent::CursorLeft { .. } if !self.is_hovering_files => {
+                // TODO: Encode cursor availability in the type-system
+                self.cursor_position =
+                    winit::dpi::PhysicalPosition::new(-1.0, -1.0);
+            }
+            WindowEvent::HoveredFile(_) => {
+                // `winit` does not report the cursor while files are dragged
+                // over a window, and some platforms report it leaving the
+                // window instead. We keep its last known position, which may
+                // be unknown if the files were dragged in from outside.
+                self.is_hovering_files = true;
+            }
+            WindowEvent::DroppedFile(_) => {
+                self.is_hovering_files = false;
+            }
+            WindowEvent::HoveredFileCancelled => {
+                self.is_hovering_files = false;
+
                 // TODO: Encode cursor availability in the type-system
                 self.cursor_position =
                     winit::dpi::PhysicalPosition::new(-1.0, -1.0);